@@ -19,7 +19,27 @@ impl<'l> Container<'l> {
         }
     }
 
-    pub fn generate_schema(&self, description: Option<String>) -> TokenStream {
+    pub fn has_flatten(&self) -> bool {
+        match self {
+            Self::NamedStruct { fields, .. } => fields.iter().any(|v| v.is_flatten()),
+            Self::UnnamedStruct { fields, .. } => fields.iter().any(|v| v.is_flatten()),
+            Self::Enum { .. } => false,
+        }
+    }
+
+    pub fn has_sensitive(&self) -> bool {
+        match self {
+            Self::NamedStruct { fields, .. } => fields.iter().any(|v| v.is_sensitive()),
+            Self::UnnamedStruct { fields, .. } => fields.iter().any(|v| v.is_sensitive()),
+            Self::Enum { .. } => false,
+        }
+    }
+
+    pub fn generate_schema(
+        &self,
+        description: Option<String>,
+        deny_unknown_fields: bool,
+    ) -> TokenStream {
         let description = if let Some(comment) = description {
             quote! {
                 schema.set_description(#comment);
@@ -43,9 +63,12 @@ impl<'l> Container<'l> {
 
                 quote! {
                     #description
-                    schema.structure(StructType::new([
-                        #(#schema_types),*
-                    ]))
+                    schema.structure(StructType {
+                        deny_unknown_fields: #deny_unknown_fields,
+                        ..StructType::new([
+                            #(#schema_types),*
+                        ])
+                    })
                 }
             }
             Self::UnnamedStruct { fields, .. } => {
@@ -1,12 +1,12 @@
 use crate::common::FieldValue;
 use crate::utils::{
     extract_comment, extract_common_attrs, extract_deprecated, format_case, map_bool_field_quote,
-    map_option_field_quote, preserve_str_literal,
+    map_option_field_quote, parse_str_or_list, preserve_str_literal,
 };
 use darling::FromAttributes;
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
-use syn::{Attribute, Expr, ExprPath, Field as NativeField, Lit, Type};
+use syn::{Attribute, Expr, ExprPath, Field as NativeField, Lit, LitStr, Type};
 
 // #[serde()]
 #[derive(FromAttributes, Default)]
@@ -30,12 +30,19 @@ pub struct FieldArgs {
     // config
     #[darling(with = "preserve_str_literal", map = "Some")]
     pub default: Option<Expr>,
-    pub env: Option<String>,
+    #[darling(with = "parse_str_or_list")]
+    pub deprecated_env: Vec<String>,
+    #[darling(with = "parse_str_or_list")]
+    pub env: Vec<String>,
+    pub env_format: Option<LitStr>,
     pub extend: bool,
     pub merge: Option<ExprPath>,
     pub nested: bool,
+    pub overrides: bool,
     pub parse_env: Option<ExprPath>,
+    pub renamed_from: Option<String>,
     pub required: bool,
+    pub sensitive: bool,
     pub validate: Option<Expr>,
 
     // serde
@@ -57,6 +64,7 @@ pub struct Field<'l> {
     pub value: &'l Type,
     pub value_type: FieldValue<'l>,
     pub env_prefix: Option<String>,
+    pub env_file_suffix: Option<String>,
 }
 
 impl<'l> Field<'l> {
@@ -78,6 +86,7 @@ impl<'l> Field<'l> {
             args,
             serde_args,
             env_prefix: None,
+            env_file_suffix: None,
         };
 
         if field.args.default.is_some() {
@@ -94,6 +103,29 @@ impl<'l> Field<'l> {
             panic!("Cannot use required with non-optional settings.");
         }
 
+        if field.args.overrides && field.is_nested() {
+            panic!("Cannot use `overrides` with `nested` configs.");
+        }
+
+        if field.is_nested()
+            && (!field.args.env.is_empty() || !field.args.deprecated_env.is_empty())
+            && !field.has_env_parser()
+        {
+            panic!("Nested configs require `env_format` or `parse_env` when using `env`.");
+        }
+
+        if field.args.env_format.is_some() && field.args.parse_env.is_some() {
+            panic!("Cannot use `env_format` and `parse_env` together.");
+        }
+
+        if field.args.sensitive && field.is_nested() {
+            panic!("Cannot use `sensitive` with `nested` configs.");
+        }
+
+        if field.args.renamed_from.is_some() && field.name.is_none() {
+            panic!("Cannot use `renamed_from` with unnamed fields.");
+        }
+
         field
     }
 
@@ -105,6 +137,18 @@ impl<'l> Field<'l> {
         self.args.extend
     }
 
+    pub fn is_overridable(&self) -> bool {
+        self.args.overrides
+    }
+
+    pub fn has_env_parser(&self) -> bool {
+        self.args.env_format.is_some() || self.args.parse_env.is_some()
+    }
+
+    pub fn is_flatten(&self) -> bool {
+        self.args.flatten || self.serde_args.flatten
+    }
+
     pub fn is_nested(&self) -> bool {
         self.args.nested
     }
@@ -117,6 +161,10 @@ impl<'l> Field<'l> {
         self.args.required
     }
 
+    pub fn is_sensitive(&self) -> bool {
+        self.args.sensitive
+    }
+
     pub fn is_skipped(&self) -> bool {
         self.args.skip || self.serde_args.skip
     }
@@ -141,16 +189,27 @@ impl<'l> Field<'l> {
         }
     }
 
-    pub fn get_env_var(&self) -> Option<String> {
-        if self.is_nested() {
-            None
-        } else if let Some(env_name) = &self.args.env {
-            Some(env_name.to_owned())
-        } else {
-            self.env_prefix
-                .as_ref()
-                .map(|env_prefix| format!("{env_prefix}{}", self.get_name(None)).to_uppercase())
+    pub fn get_env_vars(&self) -> Vec<String> {
+        // Nested configs can only be parsed from structured values
+        if self.is_nested() && !self.has_env_parser() {
+            return vec![];
         }
+
+        let mut names = if !self.args.env.is_empty() {
+            self.args.env.clone()
+        } else if let Some(env_prefix) = &self.env_prefix {
+            vec![format!("{env_prefix}{}", self.get_name(None)).to_uppercase()]
+        } else {
+            vec![]
+        };
+
+        // Deprecated names have the lowest precedence
+        names.extend(self.args.deprecated_env.iter().cloned());
+        names
+    }
+
+    pub fn get_env_file_suffix(&self) -> &str {
+        self.env_file_suffix.as_deref().unwrap_or("_FILE")
     }
 
     pub fn get_serde_meta(&self) -> Option<TokenStream> {
@@ -162,7 +221,11 @@ impl<'l> Field<'l> {
             meta.push(quote! { alias = #alias });
         }
 
-        if self.args.flatten || self.serde_args.flatten {
+        if let Some(renamed_from) = &self.args.renamed_from {
+            meta.push(quote! { alias = #renamed_from });
+        }
+
+        if self.is_flatten() {
             meta.push(quote! { flatten });
         }
 
@@ -184,6 +247,12 @@ impl<'l> Field<'l> {
                 meta.push(quote! { skip_serializing });
             } else {
                 meta.push(quote! { skip_serializing_if = "Option::is_none" });
+
+                if self.is_sensitive() {
+                    meta.push(
+                        quote! { serialize_with = "schematic::internal::serialize_sensitive" },
+                    );
+                }
             }
 
             if self.args.skip_deserializing || self.serde_args.skip_deserializing {
@@ -201,11 +270,33 @@ impl<'l> Field<'l> {
     }
 
     pub fn generate_schema_type(&self) -> TokenStream {
+        let flatten = map_bool_field_quote("flatten", self.is_flatten());
         let hidden = map_bool_field_quote("hidden", self.is_skipped());
         let nullable = map_bool_field_quote("nullable", self.is_optional());
+        let write_only = map_bool_field_quote("write_only", self.is_sensitive());
         let description = map_option_field_quote("description", extract_comment(&self.attrs));
         let deprecated = map_option_field_quote("deprecated", extract_deprecated(&self.attrs));
-        let env_var = map_option_field_quote("env_var", self.get_env_var());
+        let env_vars = self.get_env_vars();
+        let env_var = if env_vars.is_empty() {
+            None
+        } else {
+            Some(quote! {
+                field.env_var = vec![#(#env_vars.into()),*];
+            })
+        };
+        let deprecated_env_vars = &self.args.deprecated_env;
+        let deprecated_env_var = if deprecated_env_vars.is_empty() {
+            None
+        } else {
+            Some(quote! {
+                field.deprecated_env_var = vec![#(#deprecated_env_vars.into()),*];
+            })
+        };
+        let renamed_from = map_option_field_quote("renamed_from", self.args.renamed_from.as_ref());
+        let alias = map_option_field_quote(
+            "alias",
+            self.args.alias.as_ref().or(self.serde_args.alias.as_ref()),
+        );
 
         let value = self.value;
         let mut inner_schema = if self.is_nested() {
@@ -238,22 +329,32 @@ impl<'l> Field<'l> {
             inner_schema = quote! { schema.infer_with_default::<#value>(#lit_value) };
         }
 
-        let value = if description.is_none()
+        let value = if alias.is_none()
+            && description.is_none()
             && deprecated.is_none()
             && env_var.is_none()
+            && deprecated_env_var.is_none()
+            && renamed_from.is_none()
+            && flatten.is_none()
             && hidden.is_none()
             && nullable.is_none()
+            && write_only.is_none()
         {
             inner_schema
         } else {
             quote! {
                 {
                     let mut field = #inner_schema;
+                    #alias
                     #description
                     #deprecated
                     #env_var
+                    #deprecated_env_var
+                    #renamed_from
+                    #flatten
                     #hidden
                     #nullable
+                    #write_only
                     field
                 }
             }
@@ -292,7 +393,16 @@ impl<'l> ToTokens for Field<'l> {
                 pub #name: #value,
             });
         } else {
+            // Unnamed fields don't support most serde attributes,
+            // but sensitive values must still be redacted
+            let redact = if self.is_sensitive() {
+                quote! { #[serde(serialize_with = "schematic::internal::serialize_sensitive")] }
+            } else {
+                quote! {}
+            };
+
             tokens.extend(quote! {
+                #redact
                 pub #value,
             });
         }
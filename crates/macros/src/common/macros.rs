@@ -10,6 +10,7 @@ use syn::{Attribute, Data, DeriveInput, ExprPath, Fields};
 #[darling(default, allow_unknown_fields, attributes(serde))]
 pub struct ContainerSerdeArgs {
     // struct
+    pub deny_unknown_fields: bool,
     pub rename: Option<String>,
     pub rename_all: Option<String>,
 
@@ -31,8 +32,10 @@ pub struct MacroArgs {
     // config
     pub allow_unknown_fields: bool,
     pub context: Option<ExprPath>,
+    pub env_file_suffix: Option<String>,
     pub env_prefix: Option<String>,
     pub file: Option<String>,
+    pub profiles: bool,
 
     // serde
     pub rename: Option<String>,
@@ -77,6 +80,7 @@ impl<'l> Macro<'l> {
                                 let mut field = Field::from(f);
                                 field.casing_format.clone_from(&casing_format);
                                 field.env_prefix.clone_from(&args.env_prefix);
+                                field.env_file_suffix.clone_from(&args.env_file_suffix);
                                 field
                             })
                             .collect::<Vec<_>>(),
@@ -97,6 +101,7 @@ impl<'l> Macro<'l> {
                                 field.index = index;
                                 field.casing_format.clone_from(&casing_format);
                                 field.env_prefix.clone_from(&args.env_prefix);
+                                field.env_file_suffix.clone_from(&args.env_file_suffix);
                                 field
                             })
                             .collect::<Vec<_>>(),
@@ -150,6 +155,10 @@ impl<'l> Macro<'l> {
             }
         };
 
+        if args.profiles && !matches!(config_type, Container::NamedStruct { .. }) {
+            panic!("Profiles are only supported for structs with named fields.");
+        }
+
         Self {
             args,
             serde_args,
@@ -160,16 +169,34 @@ impl<'l> Macro<'l> {
         }
     }
 
+    // Serde doesn't support denying unknown fields when a field is flattened,
+    // as the flattened field receives all unknown fields
+    pub fn denies_unknown_fields(&self) -> bool {
+        !self.args.allow_unknown_fields && !self.type_of.has_flatten()
+    }
+
     pub fn get_meta_struct(&self) -> TokenStream {
         let name = if let Some(rename) = &self.args.rename {
             rename.to_string()
         } else {
             format!("{}", self.name)
         };
+        let profiles = self.args.profiles;
+        let env_prefix = match &self.args.env_prefix {
+            Some(prefix) => {
+                let prefix = prefix.to_uppercase();
+                quote! { Some(#prefix) }
+            }
+            None => quote! { None },
+        };
+        let env_file_suffix = self.args.env_file_suffix.as_deref().unwrap_or("_FILE");
 
         quote! {
             schematic::Meta {
                 name: #name,
+                env_prefix: #env_prefix,
+                env_file_suffix: #env_file_suffix,
+                profiles: #profiles,
             }
         }
     }
@@ -191,7 +218,7 @@ impl<'l> Macro<'l> {
             Container::NamedStruct { .. } => {
                 meta.push(quote! { default });
 
-                if !self.args.allow_unknown_fields {
+                if self.denies_unknown_fields() {
                     meta.push(quote! { deny_unknown_fields });
                 }
             }
@@ -161,6 +161,41 @@ impl<'l> Container<'l> {
         }
     }
 
+    pub fn generate_path_overrides(&self) -> TokenStream {
+        match self {
+            Self::NamedStruct {
+                fields: settings, ..
+            } => {
+                let names = settings
+                    .iter()
+                    .filter(|setting| setting.is_overridable())
+                    .map(|setting| setting.get_name_raw())
+                    .collect::<Vec<_>>();
+
+                if names.len() > 1 {
+                    panic!(
+                        "Only 1 setting may use `overrides`, found: {}",
+                        names
+                            .iter()
+                            .map(|name| name.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    );
+                }
+
+                match names.first() {
+                    Some(name) => quote! {
+                        self.#name.clone().unwrap_or_default()
+                    },
+                    None => quote! { vec![] },
+                }
+            }
+            Self::UnnamedStruct { .. } | Self::Enum { .. } => {
+                quote! { vec![] }
+            }
+        }
+    }
+
     pub fn generate_finalize(&self) -> TokenStream {
         match self {
             Self::NamedStruct {
@@ -183,7 +218,7 @@ impl<'l> Container<'l> {
 
                     partial.merge(context, self)?;
 
-                    if let Some(data) = Self::env_values()? {
+                    if let Some(data) = Self::env_values(env)? {
                         partial.merge(context, data)?;
                     }
 
@@ -214,6 +249,58 @@ impl<'l> Container<'l> {
         }
     }
 
+    pub fn generate_redact(&self) -> TokenStream {
+        match self {
+            Self::NamedStruct {
+                fields: settings, ..
+            }
+            | Self::UnnamedStruct {
+                fields: settings, ..
+            } => {
+                let redact_stmts = settings
+                    .iter()
+                    .filter_map(|s| s.generate_redact_statement())
+                    .collect::<Vec<_>>();
+
+                quote! {
+                    #(#redact_stmts)*
+                }
+            }
+            Self::Enum { .. } => {
+                quote! {}
+            }
+        }
+    }
+
+    pub fn generate_env_vars(&self) -> TokenStream {
+        match self {
+            Self::NamedStruct {
+                fields: settings, ..
+            }
+            | Self::UnnamedStruct {
+                fields: settings, ..
+            } => {
+                let env_stmts = settings
+                    .iter()
+                    .filter_map(|s| s.generate_env_vars_statement())
+                    .collect::<Vec<_>>();
+
+                if env_stmts.is_empty() {
+                    quote! {}
+                } else {
+                    quote! {
+                        let mut vars = vec![];
+                        #(#env_stmts)*
+                        vars
+                    }
+                }
+            }
+            Self::Enum { .. } => {
+                quote! {}
+            }
+        }
+    }
+
     pub fn generate_merge(&self) -> TokenStream {
         match self {
             Self::NamedStruct {
@@ -344,17 +431,74 @@ impl<'l> Container<'l> {
         }
     }
 
+    pub fn generate_debug(&self, config_name: &Ident, partial_name: &Ident) -> TokenStream {
+        // Only implement manually when values must be redacted,
+        // otherwise the consumer is expected to derive it
+        if !self.has_sensitive() {
+            return quote! {};
+        }
+
+        let (config_fields, partial_fields) = match self {
+            Self::NamedStruct {
+                fields: settings, ..
+            } => {
+                let config_fields = settings.iter().map(|s| s.generate_debug_field(false));
+                let partial_fields = settings.iter().map(|s| s.generate_debug_field(true));
+
+                (
+                    quote! { f.debug_struct(stringify!(#config_name)) #(#config_fields)* .finish() },
+                    quote! { f.debug_struct(stringify!(#partial_name)) #(#partial_fields)* .finish() },
+                )
+            }
+            Self::UnnamedStruct {
+                fields: settings, ..
+            } => {
+                let config_fields = settings.iter().map(|s| s.generate_debug_field(false));
+                let partial_fields = settings.iter().map(|s| s.generate_debug_field(true));
+
+                (
+                    quote! { f.debug_tuple(stringify!(#config_name)) #(#config_fields)* .finish() },
+                    quote! { f.debug_tuple(stringify!(#partial_name)) #(#partial_fields)* .finish() },
+                )
+            }
+            Self::Enum { .. } => unreachable!(),
+        };
+
+        quote! {
+            #[automatically_derived]
+            impl std::fmt::Debug for #config_name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    #config_fields
+                }
+            }
+
+            #[automatically_derived]
+            impl std::fmt::Debug for #partial_name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    #partial_fields
+                }
+            }
+        }
+    }
+
     pub fn generate_partial(
         &self,
         partial_name: &Ident,
         partial_attrs: &[TokenStream],
     ) -> TokenStream {
+        // Debug is implemented manually when values must be redacted
+        let debug = if self.has_sensitive() {
+            quote! {}
+        } else {
+            quote! { Debug, }
+        };
+
         match self {
             Self::NamedStruct {
                 fields: settings, ..
             } => {
                 quote! {
-                    #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+                    #[derive(Clone, #debug Default, PartialEq, serde::Deserialize, serde::Serialize)]
                     #(#partial_attrs)*
                     pub struct #partial_name {
                         #(#settings)*
@@ -365,7 +509,7 @@ impl<'l> Container<'l> {
                 fields: settings, ..
             } => {
                 quote! {
-                    #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+                    #[derive(Clone, #debug Default, PartialEq, serde::Deserialize, serde::Serialize)]
                     #(#partial_attrs)*
                     pub struct #partial_name(
                         #(#settings)*
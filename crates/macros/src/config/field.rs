@@ -1,6 +1,6 @@
 use crate::common::{Field, FieldValue};
 use proc_macro2::{Literal, TokenStream};
-use quote::{quote, ToTokens, TokenStreamExt};
+use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
 use syn::Expr;
 
 impl<'l> Field<'l> {
@@ -12,14 +12,30 @@ impl<'l> Field<'l> {
         }
     }
 
-    pub fn generate_env_statement(&self) -> Option<TokenStream> {
-        if self.is_nested() {
-            return None;
+    pub fn generate_debug_field(&self, partial: bool) -> TokenStream {
+        let key = self.get_field_key();
+
+        let value = if !self.is_sensitive() {
+            quote! { &self.#key }
+        } else if partial || self.is_optional() {
+            quote! { &self.#key.as_ref().map(schematic::internal::Redacted) }
+        } else {
+            quote! { &schematic::internal::Redacted(&self.#key) }
+        };
+
+        if self.name.is_some() {
+            let name = self.get_field_key_string();
+
+            quote! { .field(#name, #value) }
+        } else {
+            quote! { .field(#value) }
         }
+    }
 
-        let env = self.get_env_var();
+    pub fn generate_env_statement(&self) -> Option<TokenStream> {
+        let env_vars = self.get_env_vars();
 
-        if env.is_none() {
+        if env_vars.is_empty() {
             if self.args.parse_env.is_some() {
                 panic!("Cannot use `parse_env` without `env` or a parent `env_prefix`.");
             }
@@ -27,19 +43,140 @@ impl<'l> Field<'l> {
             return None;
         };
 
-        let value = if let Some(parse_env) = &self.args.parse_env {
-            quote! {
-                parse_from_env_var(#env, #parse_env)?
-            }
+        let keys = quote! { &[#(#env_vars),*] };
+
+        let file_suffix = self.get_env_file_suffix();
+
+        let parser = if let Some(parse_env) = &self.args.parse_env {
+            Some(quote! { #parse_env })
         } else {
-            quote! {
-                default_from_env_var(#env)?
-            }
+            self.args
+                .env_format
+                .as_ref()
+                .map(|env_format| match env_format.value().as_str() {
+                    // Formats are gated behind Cargo features of the schematic crate,
+                    // so defer to its macros, which error when a feature is disabled
+                    "json" => quote_spanned! { env_format.span()=> schematic::env_format_json!() },
+                    "toml" => quote_spanned! { env_format.span()=> schematic::env_format_toml!() },
+                    "yaml" => quote_spanned! { env_format.span()=> schematic::env_format_yaml!() },
+                    other => syn::Error::new_spanned(
+                        env_format,
+                        format!("Unsupported `env_format` {other}, expected json, toml, or yaml."),
+                    )
+                    .to_compile_error(),
+                })
         };
 
         let key = self.get_field_key();
 
-        Some(quote! { partial.#key = #value; })
+        // Sensitive values must not appear in error messages, so the parser
+        // is wrapped to redact the value that it was provided
+        if self.is_sensitive() {
+            let parser = parser.unwrap_or_else(|| quote! { parse_env_value });
+
+            return Some(quote! {
+                partial.#key = parse_from_env_var(
+                    env,
+                    #keys,
+                    #file_suffix,
+                    redact_env_var_error(#parser),
+                )?;
+            });
+        }
+
+        let value = match parser {
+            Some(parser) => quote! {
+                parse_from_env_var(env, #keys, #file_suffix, #parser)
+            },
+            None => quote! {
+                default_from_env_var(env, #keys, #file_suffix)
+            },
+        };
+
+        Some(quote! { partial.#key = #value?; })
+    }
+
+    pub fn generate_redact_statement(&self) -> Option<TokenStream> {
+        let key = match &self.name {
+            Some(_) => {
+                let name = self.get_name(Some(&self.casing_format));
+
+                quote! { #name }
+            }
+            None => {
+                let index = Index(self.index);
+
+                quote! { #index }
+            }
+        };
+
+        if self.is_sensitive() {
+            return Some(quote! {
+                redact_setting_value(value.get_mut(#key));
+            });
+        }
+
+        // Flattened settings are serialized into the parent
+        let value = if self.is_flatten() {
+            quote! { Some(&mut *value) }
+        } else {
+            quote! { value.get_mut(#key) }
+        };
+
+        match &self.value_type {
+            FieldValue::NestedList { item, .. } => Some(quote! {
+                redact_nested_list::<#item>(#value);
+            }),
+            FieldValue::NestedMap { value: item, .. } => Some(quote! {
+                redact_nested_map::<#item>(#value);
+            }),
+            FieldValue::NestedValue { value: item, .. } => Some(quote! {
+                redact_nested_value::<#item>(#value);
+            }),
+            FieldValue::Value { .. } => None,
+        }
+    }
+
+    pub fn generate_env_vars_statement(&self) -> Option<TokenStream> {
+        let name = if self.name.is_some() {
+            self.get_name(Some(&self.casing_format))
+        } else {
+            self.index.to_string()
+        };
+        let mut stmts = vec![];
+        let env_vars = self.get_env_vars();
+
+        if !env_vars.is_empty() {
+            let deprecated_env_vars = &self.args.deprecated_env;
+
+            stmts.push(quote! {
+                vars.push(schematic::SettingEnvVars {
+                    path: schematic::Path::default().join_key(#name),
+                    names: vec![#(#env_vars),*],
+                    deprecated: vec![#(#deprecated_env_vars),*],
+                });
+            });
+        }
+
+        // Nested configs read their own variables when finalized
+        let nested = match &self.value_type {
+            FieldValue::NestedList { item, .. } => Some(item),
+            FieldValue::NestedMap { value, .. } => Some(value),
+            FieldValue::NestedValue { value, .. } => Some(value),
+            FieldValue::Value { .. } => None,
+        };
+
+        if let Some(nested) = nested {
+            stmts.push(quote! {
+                vars.extend(nested_env_vars::<#nested>(#name));
+            });
+        }
+
+        if stmts.is_empty() {
+            None
+        } else {
+            Some(quote! { #(#stmts)* })
+        }
     }
 
     pub fn generate_finalize_statement(&self) -> TokenStream {
@@ -130,8 +267,16 @@ impl<'l> Field<'l> {
                 }
             };
 
+            // Sensitive values must not appear in error messages
+            let redact = if self.is_sensitive() {
+                quote! { let error = schematic::internal::redact_validate_error(error, setting); }
+            } else {
+                quote! {}
+            };
+
             stmts.push(quote! {
                 if let Err(error) = #func(setting, self, context, finalize) {
+                    #redact
                     errors.push(schematic::ValidateErrorType::setting(
                         path.join_key(#key_quoted),
                         error,
@@ -45,9 +45,11 @@ impl<'l> FieldValue<'l> {
     pub fn get_finalize_value(&self) -> Option<TokenStream> {
         match self {
             Self::NestedList { .. } | Self::NestedMap { .. } => {
-                Some(self.map_data(quote! { value.finalize(context)? }))
+                Some(self.map_data(quote! { value.finalize_with_env(context, env)? }))
+            }
+            Self::NestedValue { .. } => {
+                Some(self.map_data(quote! { data.finalize_with_env(context, env)? }))
             }
-            Self::NestedValue { .. } => Some(self.map_data(quote! { data.finalize(context)? })),
             Self::Value { .. } => None,
         }
     }
@@ -19,9 +19,11 @@ impl<'l> ToTokens for ConfigMacro<'l> {
         let partial_name = format_ident!("Partial{}", cfg.name);
         let partial_attrs = cfg.get_partial_attrs();
         let partial = cfg.type_of.generate_partial(&partial_name, &partial_attrs);
+        let debug = cfg.type_of.generate_debug(name, &partial_name);
 
         tokens.extend(quote! {
             #partial
+            #debug
         });
 
         // Generate implementations
@@ -30,9 +32,36 @@ impl<'l> ToTokens for ConfigMacro<'l> {
         let env_values = cfg.type_of.generate_env_values();
         let extends_from = cfg.type_of.generate_extends_from();
         let finalize = cfg.type_of.generate_finalize();
+        let path_overrides = cfg.type_of.generate_path_overrides();
         let merge = cfg.type_of.generate_merge();
         let validate = cfg.type_of.generate_validate();
         let from_partial = cfg.type_of.generate_from_partial(&partial_name);
+        let redact = cfg.type_of.generate_redact();
+        let env_vars = cfg.type_of.generate_env_vars();
+
+        // Only implement when settings have variables, otherwise use the default
+        let env_vars = if env_vars.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                fn env_vars() -> Vec<schematic::SettingEnvVars> {
+                    use schematic::internal::*;
+                    #env_vars
+                }
+            }
+        };
+
+        // Only implement when settings must be redacted, otherwise use the default
+        let redact_settings = if redact.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                fn redact_settings(value: &mut schematic::internal::serde_json::Value) {
+                    use schematic::internal::*;
+                    #redact
+                }
+            }
+        };
         let instrument = instrument_quote();
 
         let context = match cfg.args.context.as_ref() {
@@ -52,7 +81,7 @@ impl<'l> ToTokens for ConfigMacro<'l> {
                 }
 
                 #instrument
-                fn env_values() -> Result<Option<Self>, schematic::ConfigError> {
+                fn env_values(env: &dyn schematic::EnvProvider) -> Result<Option<Self>, schematic::ConfigError> {
                     use schematic::internal::*;
                     #env_values
                 }
@@ -63,7 +92,11 @@ impl<'l> ToTokens for ConfigMacro<'l> {
                 }
 
                 #instrument
-                fn finalize(self, context: &Self::Context) -> Result<Self, schematic::ConfigError> {
+                fn finalize_with_env(
+                    self,
+                    context: &Self::Context,
+                    env: &dyn schematic::EnvProvider,
+                ) -> Result<Self, schematic::ConfigError> {
                     #finalize
                 }
 
@@ -77,6 +110,11 @@ impl<'l> ToTokens for ConfigMacro<'l> {
                     #merge
                 }
 
+                #instrument
+                fn path_overrides(&self) -> Vec<schematic::PathOverride<Self>> {
+                    #path_overrides
+                }
+
                 #instrument
                 fn validate_with_path(
                     &self,
@@ -121,6 +159,10 @@ impl<'l> ToTokens for ConfigMacro<'l> {
                 fn from_partial(partial: Self::Partial) -> Self {
                     #from_partial
                 }
+
+                #redact_settings
+
+                #env_vars
             }
         });
 
@@ -129,7 +171,20 @@ impl<'l> ToTokens for ConfigMacro<'l> {
             use crate::utils::extract_comment;
 
             let schema_name = cfg.get_name();
-            let schema_impl = cfg.type_of.generate_schema(extract_comment(&cfg.attrs));
+            let mut schema_impl = cfg
+                .type_of
+                .generate_schema(extract_comment(&cfg.attrs), cfg.denies_unknown_fields());
+
+            if cfg.args.profiles {
+                schema_impl = quote! {
+                    let mut config_schema = { #schema_impl };
+                    let profiles_schema = schema.infer::<std::collections::BTreeMap<String, #partial_name>>();
+
+                    schematic::internal::insert_profiles_schema(&mut config_schema, profiles_schema);
+
+                    config_schema
+                };
+            }
 
             let partial_schema_name = partial_name.to_string();
             let partial_schema_impl = cfg.type_of.generate_partial_schema(name, &partial_name);
@@ -38,7 +38,7 @@ impl<'l> Variant<'l> {
                     let stmts = outer_names
                         .iter()
                         .map(|o| {
-                            quote! { #o.finalize(context)? }
+                            quote! { #o.finalize_with_env(context, env)? }
                         })
                         .collect::<Vec<_>>();
 
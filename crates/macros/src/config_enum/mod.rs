@@ -13,6 +13,7 @@ use syn::{parse_macro_input, Data, DeriveInput};
 #[darling(default, attributes(config), supports(enum_unit, enum_tuple))]
 pub struct ConfigEnumArgs {
     before_parse: Option<String>,
+    value_enum: bool,
 
     // serde
     rename: Option<String>,
@@ -52,6 +53,10 @@ pub fn macro_impl(item: TokenStream) -> TokenStream {
 
     // Render variants to tokens
     let mut unit_names = vec![];
+    #[cfg(feature = "value_enum")]
+    let mut value_variant_names = vec![];
+    #[cfg(feature = "value_enum")]
+    let mut possible_values = vec![];
     let mut display_stmts = vec![];
     let mut from_stmts = vec![];
     let mut schema_types = vec![];
@@ -60,6 +65,18 @@ pub fn macro_impl(item: TokenStream) -> TokenStream {
 
     for (index, variant) in variants.into_iter().enumerate() {
         unit_names.push(variant.get_unit_name());
+
+        #[cfg(feature = "value_enum")]
+        if args.value_enum {
+            if !variant.args.fallback {
+                let name = variant.name;
+
+                value_variant_names.push(quote! { #enum_name::#name });
+            }
+
+            possible_values.push(variant.get_possible_value());
+        }
+
         display_stmts.push(variant.get_display_fmt());
         from_stmts.push(variant.get_from_str());
         schema_types.push(variant.get_schema_type());
@@ -110,6 +127,9 @@ pub fn macro_impl(item: TokenStream) -> TokenStream {
         impl schematic::ConfigEnum for #enum_name {
             const META: schematic::Meta = schematic::Meta {
                 name: #meta_name,
+                env_prefix: None,
+                env_file_suffix: "_FILE",
+                profiles: false,
             };
 
             fn variants() -> Vec<#enum_name> {
@@ -169,6 +189,49 @@ pub fn macro_impl(item: TokenStream) -> TokenStream {
         }
     });
 
+    #[cfg(not(feature = "value_enum"))]
+    if args.value_enum {
+        panic!("The `value_enum` Cargo feature is required for `#[config(value_enum)]`.");
+    }
+
+    #[cfg(feature = "value_enum")]
+    if args.value_enum {
+        impls.push(quote! {
+            #[automatically_derived]
+            impl schematic::internal::clap::ValueEnum for #enum_name {
+                fn value_variants<'a>() -> &'a [Self] {
+                    const VARIANTS: &[#enum_name] = &[
+                        #(#value_variant_names),*
+                    ];
+
+                    VARIANTS
+                }
+
+                fn from_str(input: &str, ignore_case: bool) -> Result<Self, String> {
+                    for variant in Self::value_variants() {
+                        if variant
+                            .to_possible_value()
+                            .is_some_and(|value| value.matches(input, ignore_case))
+                        {
+                            return Ok(variant.clone());
+                        }
+                    }
+
+                    // Handles fallback variants, and `before_parse`
+                    <Self as std::str::FromStr>::from_str(input).map_err(|error| error.to_string())
+                }
+
+                fn to_possible_value(&self) -> Option<schematic::internal::clap::builder::PossibleValue> {
+                    use schematic::internal::clap::builder::PossibleValue;
+
+                    Some(match self {
+                        #(#possible_values)*
+                    })
+                }
+            }
+        });
+    }
+
     #[cfg(feature = "schema")]
     {
         use crate::utils::map_option_argument_quote;
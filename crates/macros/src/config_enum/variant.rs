@@ -108,6 +108,29 @@ impl<'l> Variant<'l> {
         }
     }
 
+    #[cfg(feature = "value_enum")]
+    pub fn get_possible_value(&self) -> TokenStream {
+        let name = &self.name;
+
+        if self.args.fallback {
+            return quote! {
+                Self::#name(fallback) => PossibleValue::new(fallback.to_string()).hide(true),
+            };
+        }
+
+        let value = &self.value;
+        let help = extract_comment(&self.attrs).map(|comment| quote! { .help(#comment) });
+        let alias = self
+            .serde_args
+            .alias
+            .as_ref()
+            .map(|alias| quote! { .alias(#alias) });
+
+        quote! {
+            Self::#name => PossibleValue::new(#value) #help #alias,
+        }
+    }
+
     pub fn get_schema_type(&self) -> TokenStream {
         let name = self.name.to_string();
         let description = map_option_variant_quote("description", extract_comment(&self.attrs));
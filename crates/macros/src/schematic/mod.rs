@@ -11,7 +11,10 @@ impl<'l> ToTokens for SchematicMacro<'l> {
         let name = cfg.name;
 
         let schema_name = cfg.get_name();
-        let schema_impl = cfg.type_of.generate_schema(extract_comment(&cfg.attrs));
+        let schema_impl = cfg.type_of.generate_schema(
+            extract_comment(&cfg.attrs),
+            cfg.serde_args.deny_unknown_fields,
+        );
         let instrument = instrument_quote();
 
         tokens.extend(quote! {
@@ -36,6 +36,25 @@ pub fn preserve_str_literal(meta: &Meta) -> darling::Result<Expr> {
     }
 }
 
+pub fn parse_str_or_list(meta: &Meta) -> darling::Result<Vec<String>> {
+    let extract = |expr: &Expr| match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(value),
+            ..
+        }) => Ok(value.value()),
+        _ => Err(darling::Error::unexpected_expr_type(expr)),
+    };
+
+    match meta {
+        Meta::Path(_) => Err(darling::Error::unsupported_format("path").with_span(meta)),
+        Meta::List(_) => Err(darling::Error::unsupported_format("list").with_span(meta)),
+        Meta::NameValue(nv) => match &nv.value {
+            Expr::Array(array) => array.elems.iter().map(extract).collect(),
+            expr => Ok(vec![extract(expr)?]),
+        },
+    }
+}
+
 pub fn get_meta_path(meta: &Meta) -> &Path {
     match meta {
         Meta::Path(path) => path,
@@ -0,0 +1,271 @@
+use crate::config::inspect::unwrap_nullable;
+use crate::config::{Config, ConfigError, ParserError, Path};
+use clap::builder::{PossibleValuesParser, ValueParser};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use schematic_types::{LiteralValue, Schema, SchemaBuilder, SchemaType};
+use serde_json::{Map, Value};
+
+/// A setting that is configurable through a command line argument.
+struct ArgSetting<'s> {
+    /// Name of the long flag, and the identifier of the argument.
+    flag: String,
+
+    /// Keys of the setting, from the root config.
+    keys: Vec<&'s str>,
+
+    /// Whether multiple values are supported.
+    multiple: bool,
+
+    /// Schema of the setting's field.
+    field: &'s Schema,
+
+    /// Schema of the setting's value, or the item when `multiple`.
+    schema: &'s Schema,
+}
+
+fn to_flag_name(key: &str) -> String {
+    let mut name = String::with_capacity(key.len());
+
+    for ch in key.chars() {
+        if ch.is_uppercase() {
+            if !name.is_empty() && !name.ends_with('-') {
+                name.push('-');
+            }
+
+            name.extend(ch.to_lowercase());
+        } else if ch == '_' {
+            name.push('-');
+        } else {
+            name.push(ch);
+        }
+    }
+
+    name
+}
+
+fn is_supported_type(schema: &Schema) -> bool {
+    match &schema.ty {
+        SchemaType::Boolean(_)
+        | SchemaType::Float(_)
+        | SchemaType::Integer(_)
+        | SchemaType::String(_) => true,
+        SchemaType::Enum(enumerable) => enumerable
+            .values
+            .iter()
+            .all(|value| matches!(value, LiteralValue::String(_))),
+        _ => false,
+    }
+}
+
+// Only settings with a scalar value (or a list of scalar values) are supported,
+// while nested structs are flattened into dot separated flags.
+fn collect_settings<'s>(
+    schema: &'s Schema,
+    flag: Option<&str>,
+    keys: Vec<&'s str>,
+    results: &mut Vec<ArgSetting<'s>>,
+) {
+    let SchemaType::Struct(structure) = &schema.ty else {
+        return;
+    };
+
+    for (name, field) in &structure.fields {
+        if field.hidden {
+            continue;
+        }
+
+        let field_flag = match flag {
+            Some(parent) => format!("{parent}.{}", to_flag_name(name)),
+            None => to_flag_name(name),
+        };
+        let mut field_keys = keys.clone();
+        field_keys.push(name);
+
+        let field_schema = unwrap_nullable(field);
+
+        match &field_schema.ty {
+            SchemaType::Struct(_) => {
+                collect_settings(field_schema, Some(&field_flag), field_keys, results);
+            }
+            SchemaType::Array(array) if is_supported_type(unwrap_nullable(&array.items_type)) => {
+                results.push(ArgSetting {
+                    flag: field_flag,
+                    keys: field_keys,
+                    multiple: true,
+                    field,
+                    schema: unwrap_nullable(&array.items_type),
+                });
+            }
+            _ if is_supported_type(field_schema) => {
+                results.push(ArgSetting {
+                    flag: field_flag,
+                    keys: field_keys,
+                    multiple: false,
+                    field,
+                    schema: field_schema,
+                });
+            }
+            _ => {}
+        };
+    }
+}
+
+fn create_arg(setting: &ArgSetting) -> Arg {
+    let field = setting.field;
+    let mut arg = Arg::new(setting.flag.clone())
+        .long(setting.flag.clone())
+        .hide(field.deprecated.is_some());
+
+    if let Some(description) = &field.description {
+        arg = arg.help(description.lines().next().unwrap_or_default().to_owned());
+    }
+
+    arg = match &setting.schema.ty {
+        SchemaType::Boolean(_) => arg
+            .value_parser(value_parser!(bool))
+            .num_args(0..=1)
+            .require_equals(true)
+            .default_missing_value("true"),
+        SchemaType::Enum(enumerable) => {
+            // Only restrict values when every variant is a literal,
+            // otherwise a fallback variant may accept any value
+            let all_literal = enumerable.variants.as_ref().map_or(true, |variants| {
+                variants
+                    .iter()
+                    .all(|variant| matches!(variant.ty, SchemaType::Literal(_)))
+            });
+
+            if all_literal {
+                arg.value_parser(PossibleValuesParser::new(
+                    enumerable.values.iter().filter_map(|value| match value {
+                        LiteralValue::String(value) => Some(value.to_owned()),
+                        _ => None,
+                    }),
+                ))
+            } else {
+                arg.value_parser(ValueParser::string())
+            }
+        }
+        SchemaType::Float(_) => arg.value_parser(value_parser!(f64)),
+        SchemaType::Integer(_) => arg.value_parser(value_parser!(i64)),
+        _ => arg.value_parser(ValueParser::string()),
+    };
+
+    if setting.multiple {
+        arg.action(ArgAction::Append)
+    } else {
+        arg.action(ArgAction::Set)
+    }
+}
+
+/// Create a [`Command`] with a long flag argument for each setting in the provided
+/// configuration, including nested settings (`--parent.child`). Flags are named after
+/// the setting in kebab-case, and use the setting's description as help text.
+///
+/// Only settings with a boolean, number, string, or enum value, or a list of these values,
+/// are supported. Other settings, and hidden settings, are skipped.
+pub fn create_command<T: Config>() -> Command {
+    let schema = SchemaBuilder::build_root::<T>();
+    let mut settings = vec![];
+
+    collect_settings(&schema, None, vec![], &mut settings);
+
+    let args = settings.iter().map(create_arg).collect::<Vec<_>>();
+
+    let mut command = Command::new(T::META.name).args(args);
+
+    if let Some(description) = &schema.description {
+        command = command.about(description.to_owned());
+    }
+
+    command
+}
+
+fn coerce_value(schema: &Schema, value: &str) -> Value {
+    let coerced = match &schema.ty {
+        SchemaType::Boolean(_) => value.parse::<bool>().ok().map(Value::Bool),
+        SchemaType::Float(_) => value.parse::<f64>().ok().map(Value::from),
+        SchemaType::Integer(_) => value.parse::<i64>().ok().map(Value::from),
+        _ => None,
+    };
+
+    coerced.unwrap_or_else(|| Value::String(value.to_owned()))
+}
+
+fn insert_value(map: &mut Map<String, Value>, keys: &[&str], value: Value) {
+    let (last, parents) = keys.split_last().unwrap();
+    let mut current = map;
+
+    for key in parents {
+        current = current
+            .entry(key.to_string())
+            .or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut()
+            .unwrap();
+    }
+
+    current.insert(last.to_string(), value);
+}
+
+/// Convert the matches from a [`Command`] created with [`create_command`] into a
+/// partial configuration. Only arguments that were explicitly passed are populated.
+pub fn parse_matches<T: Config>(matches: &ArgMatches) -> Result<T::Partial, ConfigError> {
+    let schema = SchemaBuilder::build_root::<T>();
+    let mut settings = vec![];
+    let mut map = Map::new();
+
+    collect_settings(&schema, None, vec![], &mut settings);
+
+    for setting in settings {
+        let Some(raw_values) = matches.get_raw(&setting.flag) else {
+            continue;
+        };
+
+        let mut values = raw_values
+            .map(|raw| coerce_value(setting.schema, &raw.to_string_lossy()))
+            .collect::<Vec<_>>();
+
+        let value = if setting.multiple {
+            Value::Array(values)
+        } else {
+            match values.pop() {
+                Some(value) => value,
+                None => continue,
+            }
+        };
+
+        insert_value(&mut map, &setting.keys, value);
+    }
+
+    if map.is_empty() {
+        return Ok(T::Partial::default());
+    }
+
+    serde_path_to_error::deserialize(Value::Object(map)).map_err(|error| ConfigError::Parser {
+        config: "command line arguments".into(),
+        error: ParserError {
+            content: String::new(),
+            message: error.inner().to_string(),
+            path: Path::from(error.path()).to_string(),
+            first_span: None,
+            span: None,
+        },
+        help: None,
+    })
+}
+
+/// Parse the provided command line arguments (including the binary name, like
+/// [`std::env::args`]) with a [`Command`] created with [`create_command`], and
+/// convert the matches into a partial configuration.
+pub fn parse_args<T, I, S>(args: I) -> Result<T::Partial, ConfigError>
+where
+    T: Config,
+    I: IntoIterator<Item = S>,
+    S: Into<std::ffi::OsString> + Clone,
+{
+    let matches = create_command::<T>()
+        .try_get_matches_from(args)
+        .map_err(|error| ConfigError::InvalidArgs(Box::new(error)))?;
+
+    parse_matches::<T>(&matches)
+}
@@ -1,30 +1,59 @@
 use crate::config::errors::ConfigError;
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 /// A system for reading and writing to a cache for URL based configurations.
 pub trait Cacher {
     /// Read content from the cache store.
     fn read(&mut self, url: &str) -> Result<Option<String>, ConfigError>;
 
+    /// Read content from the cache store, even if it has expired. This is used
+    /// as a fallback when the URL could not be fetched.
+    fn read_stale(&mut self, _url: &str) -> Result<Option<String>, ConfigError> {
+        Ok(None)
+    }
+
     /// Write the provided content to the cache store.
     fn write(&mut self, url: &str, content: &str) -> Result<(), ConfigError>;
 }
 
 pub type BoxedCacher = Box<dyn Cacher>;
 
+/// An in-memory cache for URL based configurations. By default, content never
+/// expires, but a time-to-live can be configured with [`MemoryCache::with_ttl`],
+/// after which the content is only used as a stale fallback.
 #[derive(Default)]
-#[doc(hidden)]
 pub struct MemoryCache {
-    cache: HashMap<String, String>,
+    cache: HashMap<String, (String, Instant)>,
+    ttl: Option<Duration>,
+}
+
+impl MemoryCache {
+    /// Create a new cache, where content expires after the provided duration.
+    pub fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            cache: HashMap::new(),
+            ttl: Some(ttl),
+        }
+    }
 }
 
 impl Cacher for MemoryCache {
     fn read(&mut self, url: &str) -> Result<Option<String>, ConfigError> {
-        Ok(self.cache.get(url).map(|v| v.to_owned()))
+        Ok(self
+            .cache
+            .get(url)
+            .filter(|(_, written)| self.ttl.map_or(true, |ttl| written.elapsed() < ttl))
+            .map(|(v, _)| v.to_owned()))
+    }
+
+    fn read_stale(&mut self, url: &str) -> Result<Option<String>, ConfigError> {
+        Ok(self.cache.get(url).map(|(v, _)| v.to_owned()))
     }
 
     fn write(&mut self, url: &str, content: &str) -> Result<(), ConfigError> {
-        self.cache.insert(url.to_owned(), content.to_owned());
+        self.cache
+            .insert(url.to_owned(), (content.to_owned(), Instant::now()));
 
         Ok(())
     }
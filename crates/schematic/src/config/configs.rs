@@ -1,4 +1,6 @@
+use crate::config::environment::{EnvProvider, ProcessEnv};
 use crate::config::errors::ConfigError;
+use crate::config::overrides::PathOverride;
 use crate::config::path::Path;
 use crate::config::validator::ValidatorError;
 use crate::derive_enum;
@@ -9,8 +11,35 @@ use serde::{de::DeserializeOwned, Serialize};
 pub struct Meta {
     /// Name of the struct.
     pub name: &'static str,
+
+    /// Prefix for environment variables, when defined with `#[config(env_prefix)]`.
+    pub env_prefix: Option<&'static str>,
+
+    /// Suffix for environment variables that point to a file, defined with
+    /// `#[config(env_file_suffix)]`. Defaults to `_FILE`.
+    pub env_file_suffix: &'static str,
+
+    /// Whether the struct supports named profiles with `#[config(profiles)]`.
+    pub profiles: bool,
 }
 
+/// Environment variables that a setting is read from, as generated by `#[derive(Config)]`.
+#[doc(hidden)]
+#[derive(Clone, Debug)]
+pub struct SettingEnvVars {
+    /// Path to the setting.
+    pub path: Path,
+
+    /// Names of all variables, in order of precedence.
+    pub names: Vec<&'static str>,
+
+    /// Names of variables that are deprecated, defined with `#[setting(deprecated_env)]`.
+    pub deprecated: Vec<&'static str>,
+}
+
+/// The key that contains named profiles, when enabled with `#[config(profiles)]`.
+pub const PROFILES_KEY: &str = "profiles";
+
 /// Represents a partial configuration of the base [`Config`], with all settings marked as optional
 /// by wrapping the values in [`Option`].
 pub trait PartialConfig:
@@ -25,11 +54,12 @@ pub trait PartialConfig:
     fn default_values(context: &Self::Context) -> Result<Option<Self>, ConfigError>;
 
     /// Return a partial configuration with values populated from environment variables
-    /// for settings marked with `#[setting(env)]`. Unmarked settings will be [`None`].
+    /// for settings marked with `#[setting(env)]`, as read from the provided [`EnvProvider`].
+    /// Unmarked settings will be [`None`].
     ///
     /// If an environment variable does not exist, the value will be [`None`]. If
     /// the variable fails to parse or cast into the correct type, an error is returned.
-    fn env_values() -> Result<Option<Self>, ConfigError>;
+    fn env_values(env: &dyn EnvProvider) -> Result<Option<Self>, ConfigError>;
 
     /// When a setting is marked as extendable with `#[setting(extend)]`, this returns
     /// [`ExtendsFrom`] with the extended sources, either a list of strings or a single string.
@@ -39,8 +69,18 @@ pub trait PartialConfig:
     /// Finalize the partial configuration by consuming it and populating all fields with a value.
     /// Defaults values from [`PartialConfig::default_values`] will be applied first, followed
     /// by merging the current partial, and lastly environment variable values from
-    /// [`PartialConfig::env_values`].
-    fn finalize(self, context: &Self::Context) -> Result<Self, ConfigError>;
+    /// [`PartialConfig::env_values`], as read from the current process.
+    fn finalize(self, context: &Self::Context) -> Result<Self, ConfigError> {
+        self.finalize_with_env(context, &ProcessEnv)
+    }
+
+    /// Finalize the partial configuration like [`PartialConfig::finalize`], but read
+    /// environment variables from the provided [`EnvProvider`].
+    fn finalize_with_env(
+        self,
+        context: &Self::Context,
+        env: &dyn EnvProvider,
+    ) -> Result<Self, ConfigError>;
 
     /// Merge another partial configuration into this one and clone values when applicable. The
     /// following merge strategies are applied:
@@ -50,6 +90,12 @@ pub trait PartialConfig:
     ///     using the merge function from `#[setting(merge)]`.
     fn merge(&mut self, context: &Self::Context, next: Self) -> Result<(), ConfigError>;
 
+    /// When a setting is marked with `#[setting(overrides)]`, this returns a list of
+    /// [`PathOverride`]s, in the order they were declared. Otherwise returns an empty list.
+    fn path_overrides(&self) -> Vec<PathOverride<Self>> {
+        vec![]
+    }
+
     /// Recursively validate the configuration with the provided context.
     /// Validation should be done on the final state, after merging partials.
     fn validate(&self, context: &Self::Context, finalize: bool) -> Result<(), ValidatorError> {
@@ -76,6 +122,18 @@ pub trait Config: Sized + Schematic {
 
     /// Convert a partial configuration into a full configuration, with all values populated.
     fn from_partial(partial: Self::Partial) -> Self;
+
+    /// Internal use only, replaces the values of settings marked with `#[setting(sensitive)]`
+    /// (including nested settings) within the serialized configuration.
+    #[doc(hidden)]
+    fn redact_settings(_value: &mut serde_json::Value) {}
+
+    /// Internal use only, returns the environment variables of all settings (including
+    /// nested settings) that can be read from the environment.
+    #[doc(hidden)]
+    fn env_vars() -> Vec<SettingEnvVars> {
+        vec![]
+    }
 }
 
 /// Represents an enumerable setting for use within a [`Config`].
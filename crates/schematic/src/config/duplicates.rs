@@ -0,0 +1,191 @@
+use crate::config::inspect::find_key_spans;
+use crate::config::parser::ParserError;
+use crate::config::path::Path;
+use serde::de::{
+    self, DeserializeSeed, Deserializer, EnumAccess, IgnoredAny, MapAccess, SeqAccess,
+};
+use std::collections::HashSet;
+use std::fmt;
+
+/// Walks a self-describing format without retaining any values, and records
+/// the path of the first key that is defined more than once within the same map.
+struct KeyWalker<'a> {
+    path: Path,
+    duplicate: &'a mut Option<Path>,
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for KeyWalker<'a> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, 'a> de::Visitor<'de> for KeyWalker<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E>(self, _value: bool) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_i64<E>(self, _value: i64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_i128<E>(self, _value: i128) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_u64<E>(self, _value: u64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_u128<E>(self, _value: u128) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_f64<E>(self, _value: f64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_str<E>(self, _value: &str) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_bytes<E>(self, _value: &[u8]) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_none<E>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        self.deserialize(deserializer)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        self.deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let mut index = 0;
+
+        while seq
+            .next_element_seed(KeyWalker {
+                path: self.path.join_index(index),
+                duplicate: &mut *self.duplicate,
+            })?
+            .is_some()
+        {
+            index += 1;
+        }
+
+        Ok(())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let mut keys = HashSet::new();
+
+        while let Some(key) = map.next_key_seed(KeyName)? {
+            let path = self.path.join_key(&key);
+
+            if self.duplicate.is_none() && !keys.insert(key) {
+                *self.duplicate = Some(path.clone());
+            }
+
+            map.next_value_seed(KeyWalker {
+                path,
+                duplicate: &mut *self.duplicate,
+            })?;
+        }
+
+        Ok(())
+    }
+
+    // Enums (YAML tags) are opaque, so skip their contents
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<(), A::Error> {
+        de::Visitor::visit_enum(IgnoredAny, data).map(|_| ())
+    }
+}
+
+/// Converts a scalar map key into a string, so that keys can be compared.
+struct KeyName;
+
+impl<'de> DeserializeSeed<'de> for KeyName {
+    type Value = String;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<String, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> de::Visitor<'de> for KeyName {
+    type Value = String;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a scalar key")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<String, E> {
+        Ok(value.to_string())
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<String, E> {
+        Ok(value.to_string())
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<String, E> {
+        Ok(value.to_string())
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<String, E> {
+        Ok(value.to_string())
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<String, E> {
+        Ok(value.to_owned())
+    }
+
+    fn visit_unit<E>(self) -> Result<String, E> {
+        Ok("null".into())
+    }
+}
+
+/// Find the first key that is defined more than once within the same map,
+/// at any depth, and return its path. Syntax errors are ignored, as they
+/// will be reported when the content is parsed.
+pub(crate) fn find_duplicate_key<'de, D: Deserializer<'de>>(deserializer: D) -> Option<Path> {
+    let mut duplicate = None;
+
+    let _ = KeyWalker {
+        path: Path::default(),
+        duplicate: &mut duplicate,
+    }
+    .deserialize(deserializer);
+
+    duplicate
+}
+
+/// Create an error for the duplicate key at the provided path, with a span
+/// to both the first and second occurrences of the key.
+pub(crate) fn create_duplicate_key_error(content: &str, path: &Path) -> ParserError {
+    let spans = find_key_spans(content, path);
+
+    ParserError {
+        content: content.to_owned(),
+        path: path.to_string(),
+        first_span: spans.first().copied(),
+        span: spans.get(1).copied(),
+        message: format!("Duplicate key `{path}`."),
+    }
+}
@@ -0,0 +1,134 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+
+thread_local! {
+    static USED_ENV_VARS: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+struct TrackGuard(Option<Vec<String>>);
+
+impl Drop for TrackGuard {
+    fn drop(&mut self) {
+        USED_ENV_VARS.with(|used| used.replace(self.0.take()));
+    }
+}
+
+/// Record the name of an environment variable that a setting received its value from,
+/// when running within [`track_env_vars`].
+pub(crate) fn record_env_var(name: &str) {
+    USED_ENV_VARS.with(|used| {
+        if let Some(used) = used.borrow_mut().as_mut() {
+            if !used.iter().any(|key| key == name) {
+                used.push(name.to_owned());
+            }
+        }
+    });
+}
+
+/// Run the provided operation, and return the names of all environment variables
+/// that settings received their value from while running.
+pub(crate) fn track_env_vars<R>(op: impl FnOnce() -> R) -> (R, Vec<String>) {
+    let _guard = TrackGuard(USED_ENV_VARS.with(|used| used.replace(Some(vec![]))));
+    let result = op();
+    let names = USED_ENV_VARS.with(|used| used.take());
+
+    (result, names.unwrap_or_default())
+}
+
+/// A source of environment variables, used when loading settings marked with
+/// `#[setting(env)]`. Defaults to the current process with [`ProcessEnv`].
+pub trait EnvProvider {
+    /// Return the value of the environment variable, or [`None`] if it is not set.
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// Return all environment variables as a list of name and value pairs.
+    fn vars(&self) -> Vec<(String, String)>;
+}
+
+/// Reads environment variables from the current process.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProcessEnv;
+
+impl EnvProvider for ProcessEnv {
+    fn get(&self, key: &str) -> Option<String> {
+        env::var(key).ok()
+    }
+
+    fn vars(&self) -> Vec<(String, String)> {
+        env::vars().collect()
+    }
+}
+
+/// A point-in-time copy of environment variables, that will not be
+/// affected by later changes to the current process.
+#[derive(Clone, Debug, Default)]
+pub struct EnvSnapshot {
+    vars: HashMap<String, String>,
+}
+
+impl EnvSnapshot {
+    /// Capture all environment variables from the current process.
+    pub fn capture() -> Self {
+        Self {
+            vars: env::vars().collect(),
+        }
+    }
+
+    /// Capture environment variables from the current process whose
+    /// name starts with the provided prefix.
+    pub fn capture_with_prefix(prefix: impl AsRef<str>) -> Self {
+        let prefix = prefix.as_ref();
+
+        Self {
+            vars: env::vars()
+                .filter(|(key, _)| key.starts_with(prefix))
+                .collect(),
+        }
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for EnvSnapshot {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self {
+            vars: iter
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        }
+    }
+}
+
+impl EnvProvider for EnvSnapshot {
+    fn get(&self, key: &str) -> Option<String> {
+        self.vars.get(key).cloned()
+    }
+
+    fn vars(&self) -> Vec<(String, String)> {
+        EnvProvider::vars(&self.vars)
+    }
+}
+
+impl EnvProvider for HashMap<String, String> {
+    fn get(&self, key: &str) -> Option<String> {
+        HashMap::get(self, key).cloned()
+    }
+
+    fn vars(&self) -> Vec<(String, String)> {
+        self.iter()
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .collect()
+    }
+}
+
+impl EnvProvider for BTreeMap<String, String> {
+    fn get(&self, key: &str) -> Option<String> {
+        BTreeMap::get(self, key).cloned()
+    }
+
+    fn vars(&self) -> Vec<(String, String)> {
+        self.iter()
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .collect()
+    }
+}
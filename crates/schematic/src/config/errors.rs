@@ -1,6 +1,6 @@
 use crate::config::parser::ParserError;
 use crate::config::validator::ValidatorError;
-use miette::Diagnostic;
+use miette::{Diagnostic, SourceSpan};
 use starbase_styles::{Style, Stylize};
 use std::fmt::Display;
 use std::path::PathBuf;
@@ -28,6 +28,56 @@ pub enum ConfigError {
     #[error("Extending from a file is only allowed if the parent source is also a file.")]
     ExtendsFromParentFileOnly,
 
+    #[diagnostic(code(config::include::cycle))]
+    #[error(
+        "Circular include of {} for setting {} in {}.",
+        .include.style(Style::File),
+        .path.style(Style::Id),
+        .config.style(Style::File),
+    )]
+    IncludeCycle {
+        config: String,
+
+        #[source_code]
+        content: String,
+
+        include: String,
+
+        path: String,
+
+        #[label("Included here")]
+        span: Option<SourceSpan>,
+    },
+
+    #[diagnostic(code(config::include::failed))]
+    #[error(
+        "Failed to include {} for setting {} in {}.",
+        .include.style(Style::File),
+        .path.style(Style::Id),
+        .config.style(Style::File),
+    )]
+    IncludeFailed {
+        config: String,
+
+        #[source_code]
+        content: String,
+
+        include: String,
+
+        path: String,
+
+        #[label("Included here")]
+        span: Option<SourceSpan>,
+
+        #[source]
+        error: Box<ConfigError>,
+    },
+
+    #[cfg(feature = "args")]
+    #[diagnostic(code(config::args::invalid))]
+    #[error("Failed to parse command line arguments.")]
+    InvalidArgs(#[source] Box<clap::Error>),
+
     #[diagnostic(code(config::code::invalid))]
     #[error("Invalid raw code used as a source.")]
     InvalidCode,
@@ -52,6 +102,27 @@ pub enum ConfigError {
         error: Box<std::io::Error>,
     },
 
+    #[diagnostic(code(config::overrides::invalid_pattern))]
+    #[error("Invalid glob pattern {} in path overrides.", .pattern.style(Style::File))]
+    InvalidOverridePattern {
+        pattern: String,
+        #[source]
+        error: Box<glob::PatternError>,
+    },
+
+    #[diagnostic(code(config::overrides::invalid_value))]
+    #[error("Invalid override for setting {}. {message}", .path.style(Style::Id))]
+    InvalidOverrideValue { path: String, message: String },
+
+    #[diagnostic(code(config::overrides::unknown_setting))]
+    #[error("Unable to override unknown setting {}.", .path.style(Style::Id))]
+    UnknownOverrideSetting {
+        path: String,
+
+        #[help]
+        help: Option<String>,
+    },
+
     #[diagnostic(code(config::url::invalid))]
     #[error("Invalid URL used as a source.")]
     InvalidUrl,
@@ -73,6 +144,13 @@ pub enum ConfigError {
     #[error("Unsupported format for {0}, expected {1}.")]
     UnsupportedFormat(String, String),
 
+    #[diagnostic(
+        code(config::format::undetectable),
+        help("Use a supported file extension, or explicitly provide a format.")
+    )]
+    #[error("Unable to detect the format of {0} from its content, expected {1}.")]
+    UndetectableFormat(String, String),
+
     // Parser
     #[diagnostic(code(config::parse::failed))]
     #[error("Failed to parse {}.", .config.style(Style::File))]
@@ -89,6 +167,67 @@ pub enum ConfigError {
         help: Option<String>,
     },
 
+    #[diagnostic(code(config::parse::failed_settings))]
+    #[error(
+        "Failed to parse {} settings in {}.",
+        .errors.len(),
+        .config.style(Style::File),
+    )]
+    Parsers {
+        config: String,
+
+        #[related]
+        errors: Vec<ParserError>,
+
+        #[help]
+        help: Option<String>,
+    },
+
+    #[diagnostic(code(config::setting::unknown))]
+    #[error(
+        "Unknown setting {} in {}.",
+        .path.style(Style::Id),
+        .config.style(Style::File),
+    )]
+    UnknownSetting {
+        config: String,
+
+        #[source_code]
+        content: String,
+
+        path: String,
+
+        #[label("Unknown")]
+        span: Option<SourceSpan>,
+
+        #[help]
+        help: Option<String>,
+    },
+
+    #[diagnostic(code(config::setting::unresolved_variable))]
+    #[error(
+        "Unable to resolve variable {} for setting {} in {}.",
+        .name.style(Style::Symbol),
+        .path.style(Style::Id),
+        .config.style(Style::File),
+    )]
+    UnresolvedVariable {
+        config: String,
+
+        #[source_code]
+        content: String,
+
+        name: String,
+
+        path: String,
+
+        #[label("Unresolved")]
+        span: Option<SourceSpan>,
+
+        #[help]
+        help: Option<String>,
+    },
+
     // Validator
     #[diagnostic(code(config::validate::failed))]
     #[error("Failed to validate {}.", .config.style(Style::File))]
@@ -125,6 +264,15 @@ impl ConfigError {
                 push_end();
                 message.push_str(&inner.to_string());
             }
+            ConfigError::InvalidOverridePattern { error: inner, .. } => {
+                push_end();
+                message.push_str(&inner.to_string());
+            }
+            #[cfg(feature = "args")]
+            ConfigError::InvalidArgs(inner) => {
+                push_end();
+                message.push_str(inner.to_string().trim());
+            }
             #[cfg(feature = "url")]
             ConfigError::ReadUrlFailed { error: inner, .. } => {
                 push_end();
@@ -134,6 +282,29 @@ impl ConfigError {
                 push_end();
                 message.push_str(&inner.to_string());
             }
+            ConfigError::Parsers { errors, .. } => {
+                push_end();
+
+                for inner in errors {
+                    message.push_str(&format!("\n  {inner}"));
+                }
+            }
+            ConfigError::IncludeFailed { error: inner, .. } => {
+                push_end();
+                message.push_str(&inner.to_full_string());
+            }
+            ConfigError::UnknownSetting {
+                help: Some(help), ..
+            }
+            | ConfigError::UnknownOverrideSetting {
+                help: Some(help), ..
+            }
+            | ConfigError::UnresolvedVariable {
+                help: Some(help), ..
+            } => {
+                push_end();
+                message.push_str(help);
+            }
             ConfigError::Validator { error: inner, .. } => {
                 push_end();
                 message.push_str(&inner.to_full_string());
@@ -1,4 +1,7 @@
+#[cfg(any(feature = "json", feature = "json5", feature = "ron", feature = "yaml"))]
+use crate::config::duplicates::*;
 use crate::config::errors::ConfigError;
+use crate::config::format_parser::*;
 use crate::config::parser::*;
 use miette::{SourceOffset, SourceSpan};
 use serde::de::DeserializeOwned;
@@ -6,7 +9,7 @@ use tracing::instrument;
 
 pub use crate::format::Format;
 
-fn create_span(content: &str, line: usize, column: usize) -> SourceSpan {
+pub(crate) fn create_span(content: &str, line: usize, column: usize) -> SourceSpan {
     let offset = SourceOffset::from_location(content, line, column).offset();
     let length = 0;
 
@@ -17,8 +20,39 @@ impl Format {
     /// Detects a format from a provided value, either a file path or URL, by
     /// checking for a supported file extension.
     pub fn detect(value: &str) -> Result<Format, ConfigError> {
+        Self::detect_with_formats(value, &FormatRegistry::default())
+    }
+
+    /// Detects a format from a provided value like [`Format::detect`], but will
+    /// check the extensions of the registered custom formats first.
+    pub fn detect_with_formats(
+        value: &str,
+        formats: &FormatRegistry,
+    ) -> Result<Format, ConfigError> {
+        if let Some(parser) = formats.find(value) {
+            return Ok(Format::Custom(parser.name()));
+        }
+
         let mut available: Vec<&str> = vec![];
 
+        #[cfg(feature = "hcl")]
+        {
+            available.push("HCL");
+
+            if value.ends_with(".hcl") {
+                return Ok(Format::Hcl);
+            }
+        }
+
+        #[cfg(feature = "ini")]
+        {
+            available.push("INI");
+
+            if value.ends_with(".ini") {
+                return Ok(Format::Ini);
+            }
+        }
+
         #[cfg(feature = "json")]
         {
             available.push("JSON");
@@ -28,6 +62,33 @@ impl Format {
             }
         }
 
+        #[cfg(feature = "json5")]
+        {
+            available.push("JSON5");
+
+            if value.ends_with(".json5") || value.ends_with(".jsonc") {
+                return Ok(Format::Json5);
+            }
+        }
+
+        #[cfg(feature = "properties")]
+        {
+            available.push("properties");
+
+            if value.ends_with(".properties") {
+                return Ok(Format::Properties);
+            }
+        }
+
+        #[cfg(feature = "ron")]
+        {
+            available.push("RON");
+
+            if value.ends_with(".ron") {
+                return Ok(Format::Ron);
+            }
+        }
+
         #[cfg(feature = "toml")]
         {
             available.push("TOML");
@@ -46,17 +107,251 @@ impl Format {
             }
         }
 
+        available.extend(formats.get_names());
+
         Err(ConfigError::UnsupportedFormat(
             value.to_owned(),
             available.join(", "),
         ))
     }
 
+    /// Detects a format from the provided content, by checking for syntax that is
+    /// unique to each format: an object for JSON5 or JSON, document markers or `key: value`
+    /// pairs for YAML, and tables or `key = value` pairs for TOML. This is a best
+    /// effort for sources without a file extension.
+    pub fn detect_from_content(location: &str, content: &str) -> Result<Format, ConfigError> {
+        #[allow(unused_mut)]
+        let mut available: Vec<&str> = vec![];
+
+        let content = content.trim_start_matches('\u{feff}').trim();
+        let line = content
+            .lines()
+            .map(|line| line.trim())
+            .find(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with("//"))
+            .unwrap_or_default();
+
+        #[allow(unused_variables)]
+        let is_pair = |separator: char| {
+            line.split_once(separator).is_some_and(|(key, _)| {
+                let key = key.trim_end();
+
+                !key.is_empty()
+                    && key.chars().all(|ch| {
+                        ch.is_alphanumeric() || matches!(ch, '_' | '-' | '.' | '"' | '\'')
+                    })
+            })
+        };
+
+        // JSON5 is a superset of JSON, and also supports comments
+        #[cfg(feature = "json5")]
+        {
+            available.push("JSON5");
+
+            if line.starts_with('{') {
+                return Ok(Format::Json5);
+            }
+        }
+
+        #[cfg(feature = "json")]
+        {
+            available.push("JSON");
+
+            if line.starts_with('{') {
+                return Ok(Format::Json);
+            }
+        }
+
+        #[cfg(feature = "yaml")]
+        {
+            available.push("YAML");
+
+            // Empty or comment only content is valid YAML
+            if line.is_empty() || content.starts_with("---") || content.starts_with("%YAML") {
+                return Ok(Format::Yaml);
+            }
+        }
+
+        #[cfg(feature = "toml")]
+        {
+            available.push("TOML");
+
+            if line.is_empty() || (line.starts_with('[') && line.ends_with(']')) || is_pair('=') {
+                return Ok(Format::Toml);
+            }
+        }
+
+        #[cfg(feature = "yaml")]
+        {
+            if is_pair(':') || line.starts_with("- ") {
+                return Ok(Format::Yaml);
+            }
+        }
+
+        Err(ConfigError::UndetectableFormat(
+            location.to_owned(),
+            available.join(", "),
+        ))
+    }
+
+    /// Converts an HTTP `Content-Type` header value into a format, if supported.
+    /// Structured syntax suffixes, like `application/ld+json`, are also supported.
+    pub fn from_content_type(value: &str) -> Option<Format> {
+        let mime = value.split(';').next().unwrap_or_default().trim();
+        let (_, subtype) = mime.split_once('/')?;
+        let subtype = subtype.rsplit('+').next().unwrap_or(subtype);
+
+        match subtype.to_lowercase().as_str() {
+            #[cfg(feature = "json")]
+            "json" => Some(Format::Json),
+            #[cfg(feature = "json5")]
+            "json5" => Some(Format::Json5),
+            #[cfg(feature = "toml")]
+            "toml" | "x-toml" => Some(Format::Toml),
+            #[cfg(feature = "yaml")]
+            "yaml" | "x-yaml" => Some(Format::Yaml),
+            _ => None,
+        }
+    }
+
+    /// Split the provided content into multiple documents, if supported by the
+    /// format (YAML only), otherwise returns the content as a single document.
+    /// To preserve spans in error messages, each document retains the positions
+    /// of the original content, with other documents replaced by whitespace.
+    pub fn split_documents(&self, content: &str) -> Vec<String> {
+        if !self.is_yaml() {
+            return vec![content.to_owned()];
+        }
+
+        let mut ranges = vec![];
+        let mut start = 0;
+        let mut offset = 0;
+
+        for line in content.split_inclusive('\n') {
+            let marker = line.trim_end();
+
+            // Documents start with `---`, and optionally end with `...`
+            if marker == "---" || marker.starts_with("--- ") {
+                ranges.push(start..offset);
+                start = offset;
+            } else if marker == "..." {
+                ranges.push(start..offset);
+                start = offset + line.len();
+            }
+
+            offset += line.len();
+        }
+
+        ranges.push(start..content.len());
+
+        let documents = ranges
+            .into_iter()
+            .filter(|range| {
+                content[range.clone()].lines().any(|line| {
+                    let line = line.trim();
+
+                    !line.is_empty() && line != "---" && !line.starts_with('#')
+                })
+            })
+            .map(|range| {
+                let mut document = String::with_capacity(content.len());
+
+                for (index, ch) in content.char_indices() {
+                    if ch == '\n' || range.contains(&index) {
+                        document.push(ch);
+                    } else {
+                        document.push_str(&" ".repeat(ch.len_utf8()));
+                    }
+                }
+
+                document
+            })
+            .collect::<Vec<_>>();
+
+        if documents.is_empty() {
+            return vec![content.to_owned()];
+        }
+
+        documents
+    }
+
+    /// Check the provided content for keys that are defined more than once within
+    /// the same map, at any depth, as most formats will silently keep the last value.
+    /// TOML, HCL, INI, and properties always reject duplicate keys when parsing,
+    /// while custom formats are not checked.
+    pub fn check_duplicate_keys(&self, content: &str) -> Result<(), ParserError> {
+        self.check_duplicate_keys_with_formats(content, &FormatRegistry::default())
+    }
+
+    /// Check the provided content for duplicate keys like [`Format::check_duplicate_keys`],
+    /// but will check custom formats with [`FormatParser::check_duplicate_keys`].
+    pub(crate) fn check_duplicate_keys_with_formats(
+        &self,
+        content: &str,
+        formats: &FormatRegistry,
+    ) -> Result<(), ParserError> {
+        let duplicate: Option<crate::config::Path> = match self {
+            #[cfg(feature = "json")]
+            Format::Json => find_duplicate_key(&mut serde_json::Deserializer::from_str(content)),
+
+            #[cfg(feature = "json5")]
+            Format::Json5 => json5::Deserializer::from_str(content)
+                .ok()
+                .and_then(|mut de| find_duplicate_key(&mut de)),
+
+            #[cfg(feature = "ron")]
+            Format::Ron => ron::Deserializer::from_str(content)
+                .ok()
+                .and_then(|mut de| find_duplicate_key(&mut de)),
+
+            #[cfg(feature = "yaml")]
+            Format::Yaml => find_duplicate_key(serde_yaml::Deserializer::from_str(content)),
+
+            Format::Custom(name) => {
+                return match formats.get(name) {
+                    Some(parser) => parser.check_duplicate_keys(content),
+                    None => Ok(()),
+                };
+            }
+
+            _ => None,
+        };
+
+        match duplicate {
+            #[cfg(any(feature = "json", feature = "json5", feature = "ron", feature = "yaml"))]
+            Some(path) => Err(create_duplicate_key_error(content, &path)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Parse the provided content like [`Format::parse`], but in strict mode, which
+    /// will also fail with spans to both occurrences of a duplicate key.
+    pub fn parse_strict<D>(&self, content: String, location: &str) -> Result<D, ParserError>
+    where
+        D: DeserializeOwned,
+    {
+        self.check_duplicate_keys(&content)?;
+        self.parse(content, location)
+    }
+
     /// Parse the provided content in the defined format into a partial configuration struct.
     /// On failure, will attempt to extract the path to the problematic field and source
     /// code spans (for use in `miette`).
-    #[instrument(name = "parse_format", skip(content), fields(format = ?self))]
-    pub fn parse<D>(&self, content: String, _location: &str) -> Result<D, ParserError>
+    pub fn parse<D>(&self, content: String, location: &str) -> Result<D, ParserError>
+    where
+        D: DeserializeOwned,
+    {
+        self.parse_with_formats(content, location, &FormatRegistry::default())
+    }
+
+    /// Parse the provided content like [`Format::parse`], but will parse custom formats
+    /// with the registered [`FormatParser`].
+    #[instrument(name = "parse_format", skip(content, formats), fields(format = ?self))]
+    pub(crate) fn parse_with_formats<D>(
+        &self,
+        content: String,
+        _location: &str,
+        formats: &FormatRegistry,
+    ) -> Result<D, ParserError>
     where
         D: DeserializeOwned,
     {
@@ -64,6 +359,12 @@ impl Format {
             Format::None => {
                 unreachable!();
             }
+            #[cfg(feature = "hcl")]
+            Format::Hcl => crate::config::hcl::parse_hcl(&content)?,
+
+            #[cfg(feature = "ini")]
+            Format::Ini => crate::config::key_value::parse_ini(&content)?,
+
             #[cfg(feature = "json")]
             Format::Json => {
                 let content = if content.is_empty() {
@@ -78,6 +379,7 @@ impl Format {
                     // content: NamedSource::new(location, content.to_owned()),
                     content: content.to_owned(),
                     path: error.path().to_string(),
+                    first_span: None,
                     span: Some(create_span(
                         &content,
                         error.inner().line(),
@@ -87,6 +389,88 @@ impl Format {
                 })?
             }
 
+            #[cfg(feature = "json5")]
+            Format::Json5 => {
+                let content = if content.is_empty() {
+                    "{}".to_owned()
+                } else {
+                    content
+                };
+
+                let create_error = |path: String, error: json5::Error| {
+                    let json5::Error::Message { msg, location } = error;
+
+                    // Syntax errors are rendered as a multi-line code frame,
+                    // so extract the reason and format it like the JSON branch
+                    let message = match (msg.rsplit_once("= "), &location) {
+                        (Some((_, reason)), Some(loc)) if msg.contains('\n') => {
+                            format!("{reason} at line {} column {}", loc.line, loc.column)
+                        }
+                        _ => msg,
+                    };
+
+                    ParserError {
+                        // content: NamedSource::new(location, content.to_owned()),
+                        content: content.to_owned(),
+                        path,
+                        first_span: None,
+                        span: location.map(|loc| create_span(&content, loc.line, loc.column)),
+                        message,
+                    }
+                };
+
+                let mut de = json5::Deserializer::from_str(&content)
+                    .map_err(|error| create_error(String::new(), error))?;
+
+                serde_path_to_error::deserialize(&mut de)
+                    .map_err(|error| create_error(error.path().to_string(), error.into_inner()))?
+            }
+
+            #[cfg(feature = "properties")]
+            Format::Properties => crate::config::key_value::parse_properties(&content)?,
+
+            #[cfg(feature = "ron")]
+            Format::Ron => {
+                use ron::extensions::Extensions;
+
+                let content = if content.is_empty() {
+                    "()".to_owned()
+                } else {
+                    content
+                };
+
+                let create_error = |path: String, error: ron::error::SpannedError| ParserError {
+                    // content: NamedSource::new(location, content.to_owned()),
+                    content: content.to_owned(),
+                    path,
+                    first_span: None,
+                    span: Some(create_span(
+                        &content,
+                        error.position.line,
+                        error.position.col,
+                    )),
+                    message: error.code.to_string(),
+                };
+
+                // Partial settings are all optional, so don't require `Some(...)`
+                let options =
+                    ron::Options::default().with_default_extension(Extensions::IMPLICIT_SOME);
+
+                let mut de = ron::Deserializer::from_str_with_options(&content, options)
+                    .map_err(|error| create_error(String::new(), error))?;
+
+                let data = serde_path_to_error::deserialize(&mut de).map_err(|error| {
+                    let path = error.path().to_string();
+
+                    create_error(path, de.span_error(error.into_inner()))
+                })?;
+
+                de.end()
+                    .map_err(|error| create_error(String::new(), de.span_error(error)))?;
+
+                data
+            }
+
             #[cfg(feature = "toml")]
             Format::Toml => {
                 let de = toml::Deserializer::new(&content);
@@ -95,6 +479,7 @@ impl Format {
                     // content: NamedSource::new(location, content.to_owned()),
                     content: content.to_owned(),
                     path: error.path().to_string(),
+                    first_span: None,
                     span: error.inner().span().map(|s| s.into()),
                     message: error.inner().message().to_owned(),
                 })?
@@ -111,6 +496,7 @@ impl Format {
                         // content: NamedSource::new(location, content.to_owned()),
                         content: content.to_owned(),
                         path: error.path().to_string(),
+                        first_span: None,
                         span: error
                             .inner()
                             .location()
@@ -123,10 +509,14 @@ impl Format {
                     // content: NamedSource::new(location, content.to_owned()),
                     content: content.to_owned(),
                     path: String::new(),
+                    first_span: None,
                     span: error.location().map(|s| (s.line(), s.column()).into()),
                     message: error.to_string(),
                 })?;
 
+                // Convert `!include` tags into include objects
+                convert_include_tags(&mut result);
+
                 // Second pass, convert value to struct
                 let de = result.into_deserializer();
 
@@ -134,6 +524,7 @@ impl Format {
                     // content: NamedSource::new(location, content.to_owned()),
                     content: content.to_owned(),
                     path: error.path().to_string(),
+                    first_span: None,
                     span: error
                         .inner()
                         .location()
@@ -141,8 +532,39 @@ impl Format {
                     message: error.inner().to_string(),
                 })?
             }
+
+            Format::Custom(name) => parse_custom(formats, name, &content)?,
         };
 
         Ok(data)
     }
 }
+
+#[cfg(feature = "yaml")]
+fn convert_include_tags(value: &mut serde_yaml::Value) {
+    use serde_yaml::{Mapping, Value};
+
+    match value {
+        Value::Sequence(items) => {
+            for item in items {
+                convert_include_tags(item);
+            }
+        }
+        Value::Mapping(map) => {
+            for (_, item) in map.iter_mut() {
+                convert_include_tags(item);
+            }
+        }
+        Value::Tagged(tagged) if tagged.tag == "include" => {
+            let mut map = Mapping::new();
+
+            map.insert(
+                Value::String(crate::config::INCLUDE_KEY.into()),
+                tagged.value.clone(),
+            );
+
+            *value = Value::Mapping(map);
+        }
+        _ => {}
+    };
+}
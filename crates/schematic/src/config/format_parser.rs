@@ -0,0 +1,149 @@
+use crate::config::inspect::find_key_span;
+use crate::config::parser::ParserError;
+use crate::config::path::Path;
+use crate::config::source::is_builtin_source_format;
+use miette::SourceSpan;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// A parser for a custom source format, that can be registered at runtime
+/// with [`ConfigLoader::register_format`](crate::ConfigLoader::register_format),
+/// and is referenced with [`Format::Custom`](crate::Format::Custom).
+pub trait FormatParser: Send + Sync {
+    /// Unique name of the format, used in error messages and for serialization.
+    fn name(&self) -> &'static str;
+
+    /// List of file extensions (without the leading `.`) to detect the format by.
+    fn extensions(&self) -> &[&str];
+
+    /// Parse the provided content into a generic value, that will then be
+    /// deserialized into a partial configuration. Empty content should be
+    /// treated as an empty object.
+    fn parse(&self, content: &str) -> Result<Value, ParserError>;
+
+    /// Check the content for keys that are defined more than once within the same
+    /// map, when parsing in strict mode. By default, duplicate keys are not detected.
+    fn check_duplicate_keys(&self, _content: &str) -> Result<(), ParserError> {
+        Ok(())
+    }
+
+    /// Find the source code span for the setting at the provided path, for use
+    /// in diagnostics. By default, will search the content for each key in the path.
+    fn find_span(&self, content: &str, path: &Path) -> Option<SourceSpan> {
+        find_key_span(content, path)
+    }
+}
+
+/// A collection of custom format parsers, that are registered on a loader with
+/// [`ConfigLoader::register_format`](crate::ConfigLoader::register_format).
+#[derive(Clone, Default)]
+pub struct FormatRegistry {
+    parsers: Vec<Arc<dyn FormatParser>>,
+}
+
+impl FormatRegistry {
+    /// Register a custom format parser. Custom formats are detected by file extension
+    /// before built-in formats, and a parser with the same name as a previously
+    /// registered parser will replace it.
+    pub fn register(&mut self, parser: impl FormatParser + 'static) {
+        self.parsers
+            .retain(|existing| existing.name() != parser.name());
+        self.parsers.push(Arc::new(parser));
+    }
+
+    /// Return the registered format parser with the provided name.
+    pub fn get(&self, name: &str) -> Option<&dyn FormatParser> {
+        self.parsers
+            .iter()
+            .find(|parser| parser.name() == name)
+            .map(|parser| parser.as_ref())
+    }
+
+    /// Return the registered format parser that supports the file extension
+    /// of the provided value, either a file path or URL.
+    pub fn find(&self, value: &str) -> Option<&dyn FormatParser> {
+        self.parsers
+            .iter()
+            .find(|parser| {
+                parser.extensions().iter().any(|ext| {
+                    value
+                        .strip_suffix(ext)
+                        .is_some_and(|rest| rest.ends_with('.'))
+                })
+            })
+            .map(|parser| parser.as_ref())
+    }
+
+    /// Returns true if the value ends in a supported file extension,
+    /// including the extensions of registered formats.
+    pub fn is_source_format(&self, value: &str) -> bool {
+        self.find(value).is_some() || is_builtin_source_format(value)
+    }
+
+    pub(crate) fn get_names(&self) -> Vec<&'static str> {
+        self.parsers.iter().map(|parser| parser.name()).collect()
+    }
+}
+
+thread_local! {
+    // Formats of the loader that is currently loading, so that `extends` validators,
+    // which do not have access to the loader, can also detect registered formats
+    static LOADING_FORMATS: RefCell<Option<FormatRegistry>> = const { RefCell::new(None) };
+}
+
+struct LoadingGuard(Option<FormatRegistry>);
+
+impl Drop for LoadingGuard {
+    fn drop(&mut self) {
+        LOADING_FORMATS.with(|current| current.replace(self.0.take()));
+    }
+}
+
+/// Run the operation with the provided formats available to [`is_source_format`].
+pub(crate) fn with_loading_formats<R>(formats: &FormatRegistry, op: impl FnOnce() -> R) -> R {
+    let _guard =
+        LoadingGuard(LOADING_FORMATS.with(|current| current.replace(Some(formats.clone()))));
+
+    op()
+}
+
+pub(crate) fn is_loading_format(value: &str) -> bool {
+    LOADING_FORMATS.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .is_some_and(|formats| formats.find(value).is_some())
+    })
+}
+
+pub(crate) fn parse_custom<D: DeserializeOwned>(
+    formats: &FormatRegistry,
+    name: &str,
+    content: &str,
+) -> Result<D, ParserError> {
+    let Some(parser) = formats.get(name) else {
+        return Err(ParserError {
+            content: content.to_owned(),
+            path: String::new(),
+            first_span: None,
+            span: None,
+            message: format!("Format `{name}` has not been registered."),
+        });
+    };
+
+    let value = parser.parse(content)?;
+
+    serde_path_to_error::deserialize(value).map_err(|error| {
+        let path = Path::from(error.path());
+
+        ParserError {
+            content: content.to_owned(),
+            first_span: None,
+            span: parser.find_span(content, &path),
+            path: path.to_string(),
+            message: error.inner().to_string(),
+        }
+    })
+}
@@ -0,0 +1,164 @@
+use crate::config::format::create_span;
+use crate::config::inspect::find_key_span;
+use crate::config::parser::ParserError;
+use crate::config::path::Path;
+use serde::de::{
+    DeserializeOwned, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess,
+    Visitor,
+};
+use serde::forward_to_deserialize_any;
+use serde_json::Value;
+
+/// Deserialize a generic value that was previously parsed from HCL content,
+/// allowing single blocks to be deserialized as a list.
+pub fn deserialize_value<D: DeserializeOwned>(value: Value) -> Result<D, (Path, String)> {
+    serde_path_to_error::deserialize(BlockValue(value))
+        .map_err(|error| (Path::from(error.path()), error.inner().to_string()))
+}
+
+/// Parse HCL content into a partial configuration. The content is converted
+/// following the HCL JSON specification, so blocks are mapped to objects (or a list
+/// of objects when repeated), block labels are mapped to nested object keys, and
+/// expressions that can't be represented as a value (variables, functions, templates)
+/// are mapped to their `${...}` string form.
+pub fn parse_hcl<D: DeserializeOwned>(content: &str) -> Result<D, ParserError> {
+    let create_error = |path: String, span, message: String| ParserError {
+        // content: NamedSource::new(location, content.to_owned()),
+        content: content.to_owned(),
+        path,
+        first_span: None,
+        span,
+        message,
+    };
+
+    let de = ::hcl::de::Deserializer::from_str(content).map_err(|error| match error {
+        ::hcl::Error::Parse(error) => create_error(
+            String::new(),
+            Some(create_span(
+                content,
+                error.location().line(),
+                error.location().column(),
+            )),
+            error.message().to_owned(),
+        ),
+        error => create_error(String::new(), None, error.to_string()),
+    })?;
+
+    let value: Value = serde_path_to_error::deserialize(de)
+        .map_err(|error| create_error(error.path().to_string(), None, error.inner().to_string()))?;
+
+    // Blocks are only a list when repeated, so single blocks must
+    // also be accepted as a list
+    deserialize_value(value).map_err(|(path, message)| {
+        create_error(path.to_string(), find_key_span(content, &path), message)
+    })
+}
+
+/// A value that deserializes like the inner value, but an object (a single block)
+/// will be deserialized as a list with 1 item when a list is requested.
+struct BlockValue(Value);
+
+impl<'de> Deserializer<'de> for BlockValue {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Array(items) => visitor.visit_seq(BlockSeq {
+                items: items.into_iter(),
+            }),
+            Value::Object(map) => visitor.visit_map(BlockMap {
+                entries: map.into_iter(),
+                value: None,
+            }),
+            other => other.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Object(map) => visitor.visit_seq(BlockSeq {
+                items: vec![Value::Object(map)].into_iter(),
+            }),
+            other => BlockValue(other).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0.deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct BlockSeq {
+    items: std::vec::IntoIter<Value>,
+}
+
+impl<'de> SeqAccess<'de> for BlockSeq {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        self.items
+            .next()
+            .map(|item| seed.deserialize(BlockValue(item)))
+            .transpose()
+    }
+}
+
+struct BlockMap {
+    entries: serde_json::map::IntoIter,
+    value: Option<Value>,
+}
+
+impl<'de> MapAccess<'de> for BlockMap {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+
+                seed.deserialize(key.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        seed.deserialize(BlockValue(
+            self.value.take().expect("Value requested before key."),
+        ))
+    }
+}
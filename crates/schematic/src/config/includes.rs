@@ -0,0 +1,158 @@
+use crate::config::errors::{ConfigError, HandlerError};
+use crate::config::inspect::find_key_span;
+use crate::config::parser::ParserError;
+use crate::config::path::{Path, PathSegment};
+use crate::config::source::Source;
+use serde_json::Value;
+use std::fs;
+
+/// The key of an object that will be replaced by the contents of another source,
+/// when enabled with [`ConfigLoader::resolve_includes`](crate::ConfigLoader::resolve_includes).
+pub const INCLUDE_KEY: &str = "$include";
+
+/// A source that was included into the value of another source.
+pub(crate) struct Include {
+    /// Path to the setting in the root source that was replaced.
+    pub path: Path,
+
+    /// Location of the included source, for use in error messages.
+    pub location: String,
+
+    /// Contents of the included source.
+    pub content: String,
+}
+
+/// Return a unique key for the source, so that cycles can be detected
+/// regardless of how a file path was written.
+pub(crate) fn get_include_key(source: &Source) -> String {
+    match source {
+        Source::File { path, .. } => fs::canonicalize(path)
+            .unwrap_or_else(|_| path.to_path_buf())
+            .to_string_lossy()
+            .to_string(),
+        _ => source.as_str().to_owned(),
+    }
+}
+
+/// Find all objects in the value that contain an [`INCLUDE_KEY`] string,
+/// and return their path and the value to include.
+pub(crate) fn find_includes(value: &Value) -> Vec<(Path, String)> {
+    let mut includes = vec![];
+
+    find_includes_at(value, Path::default(), &mut includes);
+
+    includes
+}
+
+fn find_includes_at(value: &Value, path: Path, includes: &mut Vec<(Path, String)>) {
+    match value {
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                find_includes_at(item, path.join_index(index), includes);
+            }
+        }
+        Value::Object(map) => {
+            if let Some(Value::String(include)) = map.get(INCLUDE_KEY) {
+                includes.push((path, include.to_owned()));
+                return;
+            }
+
+            for (key, item) in map {
+                find_includes_at(item, path.join_key(key), includes);
+            }
+        }
+        _ => {}
+    };
+}
+
+/// Replace the include object at the provided path with the included value.
+/// Other settings within the include object will override the included settings.
+pub(crate) fn splice_include(
+    value: &mut Value,
+    path: &Path,
+    included: Value,
+) -> Result<(), ConfigError> {
+    let mut current = value;
+
+    for segment in path.segments() {
+        let next = match (segment, current) {
+            (PathSegment::Index(index), Value::Array(items)) => items.get_mut(*index),
+            (PathSegment::Key(key), Value::Object(map)) => map.get_mut(key),
+            _ => None,
+        };
+
+        // Paths are derived from the value, so this should never happen
+        let Some(next) = next else {
+            return Ok(());
+        };
+
+        current = next;
+    }
+
+    let Value::Object(map) = current else {
+        return Ok(());
+    };
+
+    map.remove(INCLUDE_KEY);
+
+    if map.is_empty() {
+        *current = included;
+
+        return Ok(());
+    }
+
+    let Value::Object(mut included_map) = included else {
+        return Err(ConfigError::Handler(Box::new(HandlerError::new(
+            "Only objects can be included alongside other settings.",
+        ))));
+    };
+
+    included_map.append(map);
+    *current = Value::Object(included_map);
+
+    Ok(())
+}
+
+/// If the error occurred within an included source, update the error
+/// to point to the included source, so that spans are accurate.
+pub(crate) fn remap_include_error(error: ConfigError, includes: &[Include]) -> ConfigError {
+    let ConfigError::Parser {
+        error: ParserError { path, message, .. },
+        help,
+        ..
+    } = &error
+    else {
+        return error;
+    };
+
+    let Some(setting_path) = Path::parse(path) else {
+        return error;
+    };
+
+    // Find the most deeply nested include that contains the setting
+    let Some((include, rest)) = includes
+        .iter()
+        // Nested includes are added first, so prefer them when paths are equal
+        .rev()
+        .filter_map(|include| {
+            setting_path
+                .strip_prefix(&include.path)
+                .map(|rest| (include, rest))
+        })
+        .max_by_key(|(include, _)| include.path.segments().len())
+    else {
+        return error;
+    };
+
+    ConfigError::Parser {
+        config: include.location.clone(),
+        error: ParserError {
+            content: include.content.clone(),
+            path: path.to_owned(),
+            first_span: None,
+            span: find_key_span(&include.content, &rest),
+            message: message.to_owned(),
+        },
+        help: help.to_owned(),
+    }
+}
@@ -0,0 +1,521 @@
+use crate::config::environment::EnvProvider;
+use crate::config::path::{Path, PathSegment};
+use miette::SourceSpan;
+use schematic_types::{Schema, SchemaType, StructType};
+use serde_json::Value;
+
+/// Unwrap nullable unions (`Option`s) and return the inner non-null schema.
+pub fn unwrap_nullable(schema: &Schema) -> &Schema {
+    if let SchemaType::Union(uni) = &schema.ty {
+        if uni.has_null() && uni.variants_types.len() == 2 {
+            if let Some(inner) = uni.variants_types.iter().find(|v| !v.is_null()) {
+                return unwrap_nullable(inner);
+            }
+        }
+    }
+
+    schema
+}
+
+/// Find a field within the struct by its name, or a name it's also known by.
+pub fn find_field<'s>(structure: &'s StructType, key: &str) -> Option<(&'s String, &'s Schema)> {
+    if let Some((name, field)) = structure.fields.get_key_value(key) {
+        return Some((name, field));
+    }
+
+    structure
+        .fields
+        .iter()
+        .find(|(_, field)| {
+            field.alias.as_deref() == Some(key) || field.renamed_from.as_deref() == Some(key)
+        })
+        .map(|(name, field)| (name, field.as_ref()))
+}
+
+/// Find all environment variables that start with the provided prefix, but are not
+/// a known variable (of a setting, including nested settings), and suggest the closest
+/// known variable for each. Variables that point to a file with the file suffix, and
+/// the ignored names, are considered known.
+pub fn find_unknown_env_vars(
+    known: &[&str],
+    env: &dyn EnvProvider,
+    prefix: &str,
+    file_suffix: &str,
+    ignored: &[&str],
+) -> Vec<(String, Option<String>)> {
+    let is_known = |name: &str| {
+        ignored.contains(&name)
+            || known.contains(&name)
+            || (!file_suffix.is_empty()
+                && name
+                    .strip_suffix(file_suffix)
+                    .is_some_and(|name| known.contains(&name)))
+    };
+
+    let mut results = env
+        .vars()
+        .into_iter()
+        .filter(|(name, _)| name.starts_with(prefix) && !is_known(name))
+        .map(|(name, _)| {
+            let suggestion = find_closest_name(&name, known).map(|key| key.to_string());
+
+            (name, suggestion)
+        })
+        .collect::<Vec<_>>();
+
+    results.sort();
+    results
+}
+
+/// Recursively find all settings within the provided value that have been marked
+/// as deprecated (or renamed) in the schema, and return their path and message.
+#[cfg(feature = "schema")]
+pub fn find_deprecated_settings(schema: &Schema, value: &Value) -> Vec<(Path, Option<String>)> {
+    let mut results = vec![];
+
+    walk_deprecated_settings(schema, value, Path::default(), &mut results);
+
+    results
+}
+
+#[cfg(feature = "schema")]
+fn walk_deprecated_settings(
+    schema: &Schema,
+    value: &Value,
+    path: Path,
+    results: &mut Vec<(Path, Option<String>)>,
+) {
+    match (&unwrap_nullable(schema).ty, value) {
+        (SchemaType::Struct(structure), Value::Object(map)) => {
+            for (key, item) in map {
+                let item_path = path.join_key(key);
+
+                let Some((name, field)) = find_field(structure, key) else {
+                    continue;
+                };
+
+                if field.renamed_from.as_ref() == Some(key) {
+                    results.push((item_path.clone(), Some(format!("Renamed to `{name}`."))));
+                } else if let Some(message) = &field.deprecated {
+                    results.push((
+                        item_path.clone(),
+                        if message.is_empty() {
+                            None
+                        } else {
+                            Some(message.to_owned())
+                        },
+                    ));
+                }
+
+                walk_deprecated_settings(field, item, item_path, results);
+            }
+        }
+        (SchemaType::Object(object), Value::Object(map)) => {
+            for (key, item) in map {
+                walk_deprecated_settings(&object.value_type, item, path.join_key(key), results);
+            }
+        }
+        (SchemaType::Array(array), Value::Array(items)) => {
+            for (index, item) in items.iter().enumerate() {
+                walk_deprecated_settings(&array.items_type, item, path.join_index(index), results);
+            }
+        }
+        _ => {}
+    };
+}
+
+/// Split the provided value into a separate value for each setting, where settings
+/// of nested structs are split recursively. Each value retains the keys of its parent
+/// settings, so that it can be deserialized independently of other settings.
+#[cfg(feature = "schema")]
+pub fn split_setting_values(schema: &Schema, value: &Value) -> Vec<Value> {
+    let (SchemaType::Struct(structure), Value::Object(map)) = (&unwrap_nullable(schema).ty, value)
+    else {
+        return vec![value.clone()];
+    };
+
+    let mut results = vec![];
+
+    for (key, item) in map {
+        let field = find_field(structure, key).map(|(_, field)| field);
+        let items = match field {
+            Some(field) if matches!(unwrap_nullable(field).ty, SchemaType::Struct(_)) => {
+                split_setting_values(field, item)
+            }
+            _ => vec![item.clone()],
+        };
+
+        results.extend(
+            items
+                .into_iter()
+                .map(|item| Value::Object(serde_json::Map::from_iter([(key.to_owned(), item)]))),
+        );
+    }
+
+    results
+}
+
+/// A setting that was found in a value, but does not exist in the schema.
+#[cfg(feature = "schema")]
+pub struct UnknownSetting {
+    /// Whether the parent struct denies unknown fields.
+    pub denied: bool,
+
+    /// Valid setting names for the parent struct.
+    pub expected: Vec<String>,
+
+    /// Path to the unknown setting.
+    pub path: Path,
+
+    /// The closest matching valid setting path, if any.
+    pub suggestion: Option<String>,
+}
+
+#[cfg(feature = "schema")]
+impl UnknownSetting {
+    /// Return a help message with the suggestion and valid settings.
+    pub fn get_help(&self) -> Option<String> {
+        let mut help = vec![];
+
+        if let Some(suggestion) = &self.suggestion {
+            help.push(format!("Did you mean `{suggestion}`?"));
+        }
+
+        if !self.expected.is_empty() {
+            help.push(format!(
+                "Expected one of {}.",
+                self.expected
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        if help.is_empty() {
+            None
+        } else {
+            Some(help.join(" "))
+        }
+    }
+}
+
+/// Recursively find all settings within the provided value that do not exist
+/// in the schema, and suggest the closest valid setting for each.
+#[cfg(feature = "schema")]
+pub fn find_unknown_settings(schema: &Schema, value: &Value) -> Vec<UnknownSetting> {
+    let mut results = vec![];
+
+    walk_unknown_settings(
+        schema,
+        value,
+        (schema, &Path::default()),
+        Path::default(),
+        &mut results,
+    );
+
+    results
+}
+
+// The root is the closest struct that is not within a collection,
+// and is used for finding suggestions relative to the root.
+#[cfg(feature = "schema")]
+fn walk_unknown_settings(
+    schema: &Schema,
+    value: &Value,
+    root: (&Schema, &Path),
+    path: Path,
+    results: &mut Vec<UnknownSetting>,
+) {
+    match (&unwrap_nullable(schema).ty, value) {
+        (SchemaType::Struct(structure), Value::Object(map)) => {
+            for (key, item) in map {
+                let item_path = path.join_key(key);
+
+                if let Some((_, field)) = find_field(structure, key) {
+                    walk_unknown_settings(field, item, root, item_path, results);
+                    continue;
+                }
+
+                // Unknown keys are captured by flattened fields
+                if structure.fields.values().any(|field| field.flatten) {
+                    continue;
+                }
+
+                let (root_schema, root_path) = root;
+                let mut candidates = vec![];
+
+                collect_setting_paths(root_schema, Path::default(), &mut candidates);
+
+                results.push(UnknownSetting {
+                    denied: structure.deny_unknown_fields,
+                    expected: structure
+                        .fields
+                        .iter()
+                        .filter(|(_, field)| !field.hidden)
+                        .map(|(name, _)| name.to_owned())
+                        .collect(),
+                    suggestion: find_closest_path(
+                        &Path::new(item_path.segments()[root_path.segments().len()..].to_vec()),
+                        &candidates,
+                    )
+                    .map(|candidate| root_path.join_path(candidate).to_string()),
+                    path: item_path,
+                });
+            }
+        }
+        (SchemaType::Object(object), Value::Object(map)) => {
+            for (key, item) in map {
+                let item_path = path.join_key(key);
+
+                walk_unknown_settings(
+                    &object.value_type,
+                    item,
+                    (&object.value_type, &item_path),
+                    item_path.clone(),
+                    results,
+                );
+            }
+        }
+        (SchemaType::Array(array), Value::Array(items)) => {
+            for (index, item) in items.iter().enumerate() {
+                let item_path = path.join_index(index);
+
+                walk_unknown_settings(
+                    &array.items_type,
+                    item,
+                    (&array.items_type, &item_path),
+                    item_path.clone(),
+                    results,
+                );
+            }
+        }
+        _ => {}
+    };
+}
+
+/// Recursively find the paths of all settings in the schema, excluding hidden settings.
+#[cfg(feature = "schema")]
+pub fn find_setting_paths(schema: &Schema) -> Vec<Path> {
+    let mut results = vec![];
+
+    collect_setting_paths(schema, Path::default(), &mut results);
+
+    results
+}
+
+#[cfg(feature = "schema")]
+fn collect_setting_paths(schema: &Schema, path: Path, results: &mut Vec<Path>) {
+    if let SchemaType::Struct(structure) = &unwrap_nullable(schema).ty {
+        for (name, field) in &structure.fields {
+            if field.hidden {
+                continue;
+            }
+
+            let field_path = path.join_key(name);
+
+            collect_setting_paths(field, field_path.clone(), results);
+            results.push(field_path);
+        }
+    }
+}
+
+fn normalize_path(path: &str) -> String {
+    path.chars()
+        .filter(|ch| !matches!(ch, '.' | '_' | '-'))
+        .flat_map(|ch| ch.to_lowercase())
+        .collect()
+}
+
+// Optimal string alignment distance, which is the Levenshtein distance
+// but also supports transpositions of adjacent characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let mut matrix = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, cell) in matrix[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            matrix[i][j] = (matrix[i - 1][j] + 1)
+                .min(matrix[i][j - 1] + 1)
+                .min(matrix[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                matrix[i][j] = matrix[i][j].min(matrix[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    matrix[a.len()][b.len()]
+}
+
+/// Find the closest path to the provided path, by comparing the edit distance of
+/// both paths after normalizing casing and separators.
+#[cfg(feature = "schema")]
+pub fn find_closest_path<'p>(path: &Path, candidates: &'p [Path]) -> Option<&'p Path> {
+    let target = normalize_path(&path.to_string());
+    let threshold = (target.len() / 3).max(1);
+
+    candidates
+        .iter()
+        .map(|candidate| {
+            let distance = edit_distance(&target, &normalize_path(&candidate.to_string()));
+
+            (distance, candidate)
+        })
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Find the closest name to the provided name, using the same normalization
+/// and threshold as [`find_closest_path`].
+fn find_closest_name<'n>(name: &str, candidates: &[&'n str]) -> Option<&'n str> {
+    let target = normalize_path(name);
+    let threshold = (target.len() / 3).max(1);
+
+    candidates
+        .iter()
+        .map(|candidate| {
+            (
+                edit_distance(&target, &normalize_path(candidate)),
+                candidate,
+            )
+        })
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| *candidate)
+}
+
+/// Recursively find all settings within the provided value that did not change
+/// between the current and next states (after merging the value), and return
+/// their path.
+pub fn find_unchanged_settings(current: &Value, next: &Value, value: &Value) -> Vec<Path> {
+    let mut results = vec![];
+
+    walk_unchanged_settings(current, next, value, Path::default(), &mut results);
+
+    results
+}
+
+fn walk_unchanged_settings(
+    current: &Value,
+    next: &Value,
+    value: &Value,
+    path: Path,
+    results: &mut Vec<Path>,
+) {
+    let (Value::Object(current_map), Value::Object(next_map), Value::Object(map)) =
+        (current, next, value)
+    else {
+        return;
+    };
+
+    for (key, item) in map {
+        let (Some(current_item), Some(next_item)) = (current_map.get(key), next_map.get(key))
+        else {
+            continue;
+        };
+
+        let item_path = path.join_key(key);
+
+        if item.is_object() {
+            walk_unchanged_settings(current_item, next_item, item, item_path, results);
+        } else if current_item == next_item {
+            results.push(item_path);
+        }
+    }
+}
+
+fn is_key_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_' || ch == '-'
+}
+
+fn find_key(content: &str, key: &str, offset: usize) -> Option<usize> {
+    content[offset..]
+        .match_indices(key)
+        .map(|(index, _)| offset + index)
+        .find(|&index| {
+            let before = content[..index].chars().next_back();
+            let after = content[index + key.len()..].chars().next();
+
+            !before.is_some_and(is_key_char) && !after.is_some_and(is_key_char)
+        })
+}
+
+/// Attempt to find the span of the last key in the provided path, by sequentially
+/// searching for each key in the content. This is format agnostic, and is a best
+/// effort for use in diagnostics, so it may not always be accurate.
+pub fn find_key_span(content: &str, path: &Path) -> Option<SourceSpan> {
+    let mut offset = 0;
+    let mut span = None;
+
+    for segment in path.segments() {
+        if let PathSegment::Key(key) | PathSegment::Variant(key) = segment {
+            let index = find_key(content, key, offset)?;
+
+            offset = index + key.len();
+            span = Some((index, key.len()).into());
+        }
+    }
+
+    span
+}
+
+/// Attempt to find the spans of all occurrences of the last key in the provided path,
+/// after sequentially searching for each parent key. Like [`find_key_span`], this
+/// is a best effort for use in diagnostics, primarily for duplicate keys.
+#[cfg(any(feature = "json", feature = "json5", feature = "ron", feature = "yaml"))]
+pub fn find_key_spans(content: &str, path: &Path) -> Vec<SourceSpan> {
+    let mut keys = path.segments().iter().filter_map(|segment| match segment {
+        PathSegment::Key(key) | PathSegment::Variant(key) => Some(key),
+        _ => None,
+    });
+    let Some(last) = keys.next_back() else {
+        return vec![];
+    };
+
+    let mut offset = 0;
+    let mut spans = vec![];
+
+    for key in keys {
+        match find_key(content, key, offset) {
+            Some(index) => offset = index + key.len(),
+            None => return spans,
+        };
+    }
+
+    while let Some(index) = find_key(content, last, offset) {
+        offset = index + last.len();
+        spans.push((index, last.len()).into());
+    }
+
+    spans
+}
+
+/// Attempt to find the span of a value within the setting at the provided path,
+/// by searching for the value after the last key in the path. Like
+/// [`find_key_span`], this is a best effort for use in diagnostics.
+pub fn find_value_span(content: &str, path: &Path, value: &str) -> Option<SourceSpan> {
+    let mut offset = 0;
+
+    for segment in path.segments() {
+        if let PathSegment::Key(key) | PathSegment::Variant(key) = segment {
+            offset = find_key(content, key, offset)? + key.len();
+        }
+    }
+
+    content[offset..]
+        .find(value)
+        .map(|index| (offset + index, value.len()).into())
+}
@@ -0,0 +1,300 @@
+use crate::config::environment::EnvProvider;
+use crate::config::errors::HandlerError;
+use crate::config::inspect::{find_field, unwrap_nullable};
+use crate::config::path::Path;
+use crate::config::source::Source;
+use schematic_types::{Schema, SchemaType};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+
+/// A system for resolving the value of an interpolated variable, for a specific scheme.
+/// For example, `${file:./secret.txt}` would call the `file` resolver with `./secret.txt`.
+pub trait VariableResolver {
+    /// Resolve the value of the variable, or return [`None`] if it does not exist.
+    fn resolve(&self, value: &str, source: &Source) -> Result<Option<String>, HandlerError>;
+}
+
+impl<F> VariableResolver for F
+where
+    F: Fn(&str, &Source) -> Result<Option<String>, HandlerError>,
+{
+    fn resolve(&self, value: &str, source: &Source) -> Result<Option<String>, HandlerError> {
+        self(value, source)
+    }
+}
+
+/// Resolves variables by reading the contents of a file, relative to the source's
+/// directory. Trailing whitespace is trimmed. Only file sources are supported, so that
+/// code and URL sources cannot read local files.
+pub fn resolve_file(path: &str, source: &Source) -> Result<Option<String>, HandlerError> {
+    let Source::File {
+        path: source_path, ..
+    } = source
+    else {
+        return Err(HandlerError::new(
+            "File variables are only supported within file sources.",
+        ));
+    };
+
+    let path = match source_path.parent() {
+        Some(dir) => dir.join(path),
+        None => path.into(),
+    };
+
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    fs::read_to_string(&path)
+        .map(|content| Some(content.trim_end().to_owned()))
+        .map_err(|error| {
+            HandlerError::new(format!("Failed to read file {}: {error}", path.display()))
+        })
+}
+
+/// A variable that failed to interpolate.
+pub struct VariableError {
+    /// Optional message explaining the failure.
+    pub message: Option<String>,
+
+    /// Path to the setting that contains the variable.
+    pub path: Path,
+
+    /// The variable, including the `${}` syntax.
+    pub variable: String,
+}
+
+/// Interpolates variables within string values, using the `${name}` syntax.
+///
+/// - `${NAME}` or `${env:NAME}` - Resolves an environment variable, from the
+///   loader's [`EnvProvider`].
+/// - `${NAME:-fallback}` - Uses a fallback value if the variable is not set or empty.
+/// - `${scheme:value}` - Resolves a value using a registered resolver scheme.
+/// - `$${NAME}` - Escapes the variable, resulting in a literal `${NAME}`.
+pub struct Interpolator {
+    resolvers: HashMap<String, Box<dyn VariableResolver>>,
+}
+
+impl Default for Interpolator {
+    fn default() -> Self {
+        let mut interpolator = Self::empty();
+        interpolator.add_resolver("file", resolve_file);
+        interpolator
+    }
+}
+
+impl Interpolator {
+    /// Create a new interpolator without any resolvers, excluding environment variables.
+    pub fn empty() -> Self {
+        Self {
+            resolvers: HashMap::new(),
+        }
+    }
+
+    /// Register a resolver for the provided scheme, overwriting any existing resolver.
+    pub fn add_resolver(
+        &mut self,
+        scheme: impl AsRef<str>,
+        resolver: impl VariableResolver + 'static,
+    ) -> &mut Self {
+        self.resolvers
+            .insert(scheme.as_ref().to_owned(), Box::new(resolver));
+        self
+    }
+
+    /// Interpolate all variables within the provided string. Returns [`None`] if the
+    /// string contains no variables or escapes, and was not changed.
+    pub fn interpolate_str(
+        &self,
+        value: &str,
+        source: &Source,
+        env: &dyn EnvProvider,
+    ) -> Result<Option<String>, VariableError> {
+        if !value.contains('$') {
+            return Ok(None);
+        }
+
+        let mut result = String::with_capacity(value.len());
+        let mut changed = false;
+        let mut rest = value;
+
+        while let Some(index) = rest.find('$') {
+            result.push_str(&rest[..index]);
+            rest = &rest[index..];
+
+            // Escaped: $${NAME} -> ${NAME}
+            if rest.starts_with("$${") {
+                result.push('$');
+                rest = &rest[2..];
+                changed = true;
+                continue;
+            }
+
+            if !rest.starts_with("${") {
+                result.push('$');
+                rest = &rest[1..];
+                continue;
+            }
+
+            let Some(end) = find_closing_brace(rest) else {
+                return Err(VariableError {
+                    message: Some("Missing closing `}` for variable.".into()),
+                    path: Path::default(),
+                    variable: rest.to_owned(),
+                });
+            };
+
+            let variable = &rest[..=end];
+
+            result.push_str(&self.resolve_variable(&rest[2..end], source, env).map_err(
+                |message| VariableError {
+                    message,
+                    path: Path::default(),
+                    variable: variable.to_owned(),
+                },
+            )?);
+
+            rest = &rest[end + 1..];
+            changed = true;
+        }
+
+        result.push_str(rest);
+
+        Ok(if changed { Some(result) } else { None })
+    }
+
+    fn resolve_variable(
+        &self,
+        inner: &str,
+        source: &Source,
+        env: &dyn EnvProvider,
+    ) -> Result<String, Option<String>> {
+        let (name, fallback) = match inner.split_once(":-") {
+            Some((name, fallback)) => (name, Some(fallback)),
+            None => (inner, None),
+        };
+
+        let resolved = match name.split_once(':') {
+            Some((scheme, value)) => match self.resolvers.get(scheme) {
+                Some(resolver) => resolver.resolve(value, source),
+                None if scheme == "env" => Ok(env.get(value)),
+                None => return Err(Some(format!("Unknown variable resolver `{scheme}`."))),
+            },
+            None => Ok(env.get(name)),
+        }
+        .map_err(|error| Some(error.to_string()))?;
+
+        match (resolved, fallback) {
+            (Some(value), Some(fallback)) if value.is_empty() => Ok(fallback.to_owned()),
+            (Some(value), _) => Ok(value),
+            (None, Some(fallback)) => Ok(fallback.to_owned()),
+            (None, None) => Err(None),
+        }
+    }
+
+    /// Recursively interpolate all string values within the provided value. When a schema
+    /// is provided, interpolated strings will be coerced into numbers and booleans when
+    /// the setting expects them. Returns true if any value was changed.
+    pub fn interpolate_value(
+        &self,
+        schema: Option<&Schema>,
+        value: &mut Value,
+        source: &Source,
+        env: &dyn EnvProvider,
+        path: Path,
+    ) -> Result<bool, VariableError> {
+        let schema = schema.map(unwrap_nullable);
+
+        match value {
+            Value::String(string) => {
+                let Some(result) = self
+                    .interpolate_str(string, source, env)
+                    .map_err(|error| VariableError { path, ..error })?
+                else {
+                    return Ok(false);
+                };
+
+                *value = coerce_string(schema, result);
+
+                Ok(true)
+            }
+            Value::Array(items) => {
+                let item_schema = match schema.map(|schema| &schema.ty) {
+                    Some(SchemaType::Array(array)) => Some(array.items_type.as_ref()),
+                    _ => None,
+                };
+
+                let mut changed = false;
+
+                for (index, item) in items.iter_mut().enumerate() {
+                    changed |= self.interpolate_value(
+                        item_schema,
+                        item,
+                        source,
+                        env,
+                        path.join_index(index),
+                    )?;
+                }
+
+                Ok(changed)
+            }
+            Value::Object(map) => {
+                let mut changed = false;
+
+                for (key, item) in map.iter_mut() {
+                    let item_schema = match schema.map(|schema| &schema.ty) {
+                        Some(SchemaType::Struct(structure)) => {
+                            find_field(structure, key).map(|(_, field)| field)
+                        }
+                        Some(SchemaType::Object(object)) => Some(object.value_type.as_ref()),
+                        _ => None,
+                    };
+
+                    changed |=
+                        self.interpolate_value(item_schema, item, source, env, path.join_key(key))?;
+                }
+
+                Ok(changed)
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+// Find the `}` that closes the variable, skipping over balanced
+// braces, so that fallbacks may contain braces (`${NAME:-{}}`)
+fn find_closing_brace(value: &str) -> Option<usize> {
+    let mut depth = 0;
+
+    for (index, ch) in value.char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn coerce_string(schema: Option<&Schema>, value: String) -> Value {
+    let coerced = match schema.map(|schema| &schema.ty) {
+        Some(SchemaType::Boolean(_)) => value.parse::<bool>().ok().map(Value::Bool),
+        Some(SchemaType::Integer(_)) => value
+            .parse::<i64>()
+            .ok()
+            .map(Value::from)
+            .or_else(|| value.parse::<u64>().ok().map(Value::from)),
+        Some(SchemaType::Float(_)) => value.parse::<f64>().ok().map(Value::from),
+        _ => None,
+    };
+
+    coerced.unwrap_or(Value::String(value))
+}
@@ -0,0 +1,628 @@
+use crate::config::format::create_span;
+use crate::config::parser::ParserError;
+use crate::config::path::Path;
+use crate::format::Format;
+use indexmap::IndexMap;
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess,
+    Unexpected, Visitor,
+};
+use serde::forward_to_deserialize_any;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::mem;
+
+/// A value within a key-value document. Values are always strings,
+/// and are coerced into the type requested by the deserialized setting.
+/// Tables retain the order that keys were defined in.
+#[derive(Debug)]
+enum Entry {
+    Value(String),
+    Table(IndexMap<String, Entry>),
+}
+
+/// A parsed INI or properties document, with the location of each key.
+struct Document {
+    content: String,
+    locations: HashMap<String, (usize, usize)>,
+    root: IndexMap<String, Entry>,
+}
+
+impl Document {
+    fn new(content: &str) -> Self {
+        Self {
+            content: content.to_owned(),
+            locations: HashMap::new(),
+            root: IndexMap::new(),
+        }
+    }
+
+    fn create_error(&self, path: String, message: String, line: Option<usize>) -> ParserError {
+        let mut span = line.map(|line| create_span(&self.content, line, 1));
+
+        // Find the closest key that we have a location for
+        if span.is_none() {
+            let mut key = path.replace('[', ".").replace(']', "");
+
+            while !key.is_empty() {
+                if let Some((line, column)) = self.locations.get(&key) {
+                    span = Some(create_span(&self.content, *line, *column));
+                    break;
+                }
+
+                key.truncate(key.rfind('.').unwrap_or(0));
+            }
+        }
+
+        ParserError {
+            content: self.content.clone(),
+            path,
+            first_span: None,
+            span,
+            message,
+        }
+    }
+
+    fn insert(
+        &mut self,
+        key: &str,
+        value: String,
+        line: usize,
+        location: (usize, usize),
+    ) -> Result<(), ParserError> {
+        let parts = key.split('.').map(|part| part.trim()).collect::<Vec<_>>();
+
+        if parts.iter().any(|part| part.is_empty()) {
+            return Err(self.create_error(
+                String::new(),
+                format!("Invalid key `{key}`."),
+                Some(line),
+            ));
+        }
+
+        let mut table = &mut self.root;
+        let last_index = parts.len() - 1;
+
+        for (index, part) in parts.iter().enumerate() {
+            let path = parts[0..=index].join(".");
+
+            if index == last_index {
+                if table.contains_key(*part) {
+                    let mut error = self.create_error(
+                        path.clone(),
+                        format!("Duplicate key `{path}`."),
+                        Some(line),
+                    );
+
+                    error.first_span = self
+                        .locations
+                        .get(&path)
+                        .map(|(line, _)| create_span(&self.content, *line, 1));
+
+                    return Err(error);
+                }
+
+                table.insert(part.to_string(), Entry::Value(value));
+                self.locations.insert(path, location);
+
+                break;
+            }
+
+            let entry = table
+                .entry(part.to_string())
+                .or_insert_with(|| Entry::Table(IndexMap::new()));
+
+            table = match entry {
+                Entry::Table(inner) => inner,
+                Entry::Value(_) => {
+                    return Err(self.create_error(
+                        path.clone(),
+                        format!("Key `{path}` is a value and cannot contain nested keys."),
+                        Some(line),
+                    ));
+                }
+            };
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<D: DeserializeOwned>(mut self) -> Result<D, ParserError> {
+        let root = mem::take(&mut self.root);
+
+        serde_path_to_error::deserialize(Entry::Table(root)).map_err(|error| {
+            self.create_error(error.path().to_string(), error.inner().to_string(), None)
+        })
+    }
+}
+
+#[cfg(feature = "ini")]
+fn unquote(value: &str) -> String {
+    for quote in ['"', '\''] {
+        if value.len() >= 2 && value.starts_with(quote) && value.ends_with(quote) {
+            return value[1..value.len() - 1].to_owned();
+        }
+    }
+
+    value.to_owned()
+}
+
+/// Remove an inline `;` or `#` comment from the value, when the comment character
+/// is outside of quotes, and is at the start of the value or preceded by whitespace.
+#[cfg(feature = "ini")]
+fn strip_inline_comment(value: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+
+    for (index, ch) in value.char_indices() {
+        match quote {
+            Some(open) if ch == open => quote = None,
+            Some(_) => {}
+            None if ch == '"' || ch == '\'' => quote = Some(ch),
+            None if (ch == ';' || ch == '#') && previous.is_whitespace() => {
+                return value[0..index].trim_end();
+            }
+            None => {}
+        };
+
+        previous = ch;
+    }
+
+    value
+}
+
+/// Parse INI content into a partial configuration. Sections and dotted keys
+/// are mapped to nested structures, like `[server]` and `server.port`.
+#[cfg(feature = "ini")]
+pub fn parse_ini<D: DeserializeOwned>(content: &str) -> Result<D, ParserError> {
+    let mut document = Document::new(content);
+    let mut section = String::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let number = index + 1;
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() || trimmed.starts_with(';') || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = trimmed.strip_prefix('[') {
+            let Some(name) = name.strip_suffix(']') else {
+                return Err(document.create_error(
+                    String::new(),
+                    format!("Unclosed section header `{trimmed}`."),
+                    Some(number),
+                ));
+            };
+
+            name.trim().clone_into(&mut section);
+            continue;
+        }
+
+        let Some(separator) = trimmed.find(['=', ':']) else {
+            return Err(document.create_error(
+                String::new(),
+                format!("Expected a `key = value` pair, found `{trimmed}`."),
+                Some(number),
+            ));
+        };
+
+        let key = trimmed[0..separator].trim();
+        let value = &trimmed[separator + 1..];
+
+        // Columns are counted in characters, after the separator and any whitespace
+        let indent = line.len() - line.trim_start().len();
+        let start = indent + separator + 1 + value.len() - value.trim_start().len();
+        let column = line[0..start].chars().count() + 1;
+        let value = strip_inline_comment(value.trim());
+
+        document.insert(
+            &if section.is_empty() {
+                key.to_owned()
+            } else {
+                format!("{section}.{key}")
+            },
+            unquote(value),
+            number,
+            (number, column),
+        )?;
+    }
+
+    document.deserialize()
+}
+
+#[cfg(feature = "properties")]
+fn unescape_property(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            result.push(ch);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('t') => result.push('\t'),
+            Some('f') => result.push('\x0c'),
+            Some('u') => {
+                let code = chars.by_ref().take(4).collect::<String>();
+
+                match u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                    Some(unicode) => result.push(unicode),
+                    None => {
+                        result.push_str("\\u");
+                        result.push_str(&code);
+                    }
+                };
+            }
+            Some(other) => result.push(other),
+            None => {}
+        };
+    }
+
+    result
+}
+
+#[cfg(feature = "properties")]
+fn find_property_separator(line: &str) -> Option<(usize, usize)> {
+    let mut escaped = false;
+
+    for (index, ch) in line.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match ch {
+            '\\' => escaped = true,
+            '=' | ':' => return Some((index, index + 1)),
+            ' ' | '\t' => {
+                // Whitespace may be followed by an explicit separator
+                let rest = line[index..].trim_start();
+                let offset = line.len() - rest.len();
+
+                return Some(if rest.starts_with(['=', ':']) {
+                    (index, offset + 1)
+                } else {
+                    (index, offset)
+                });
+            }
+            _ => {}
+        };
+    }
+
+    None
+}
+
+#[cfg(feature = "properties")]
+fn count_indent(line: &str) -> usize {
+    line.chars().take_while(|ch| ch.is_whitespace()).count()
+}
+
+/// Parse Java-style properties content into a partial configuration.
+/// Dotted keys are mapped to nested structures, like `server.port`.
+#[cfg(feature = "properties")]
+pub fn parse_properties<D: DeserializeOwned>(content: &str) -> Result<D, ParserError> {
+    let mut document = Document::new(content);
+    let mut lines = content.lines().enumerate();
+
+    while let Some((index, line)) = lines.next() {
+        let number = index + 1;
+        let mut logical = line.trim_start().to_owned();
+
+        if logical.is_empty() || logical.starts_with('#') || logical.starts_with('!') {
+            continue;
+        }
+
+        // The offset of each physical line within the logical line,
+        // with its line number and starting column
+        let mut segments = vec![(0, number, count_indent(line) + 1)];
+
+        // Join lines that end with an odd number of backslashes
+        while logical.chars().rev().take_while(|ch| *ch == '\\').count() % 2 == 1 {
+            logical.pop();
+
+            match lines.next() {
+                Some((index, next)) => {
+                    segments.push((logical.len(), index + 1, count_indent(next) + 1));
+                    logical.push_str(next.trim_start());
+                }
+                None => break,
+            };
+        }
+
+        let (key, value) = match find_property_separator(&logical) {
+            Some((end, start)) => (&logical[0..end], logical[start..].trim_start()),
+            None => (logical.as_str(), ""),
+        };
+
+        let offset = logical.len() - value.len();
+        let (start, line, column) = segments
+            .iter()
+            .rev()
+            .find(|(start, _, _)| *start <= offset)
+            .copied()
+            .unwrap_or_default();
+
+        document.insert(
+            &unescape_property(key),
+            unescape_property(value),
+            number,
+            (line, column + logical[start..offset].chars().count()),
+        )?;
+    }
+
+    document.deserialize()
+}
+
+/// Return true if the format is a key-value format, whose values are always strings.
+pub fn is_key_value_format(format: &Format) -> bool {
+    match format {
+        #[cfg(feature = "ini")]
+        Format::Ini => true,
+        #[cfg(feature = "properties")]
+        Format::Properties => true,
+        _ => false,
+    }
+}
+
+/// Deserialize a generic value that was previously parsed from key-value content,
+/// coercing string values into the type requested by the deserialized setting,
+/// like [`parse_ini`] and [`parse_properties`] do.
+pub fn deserialize_value<D: DeserializeOwned>(value: Value) -> Result<D, (Path, String)> {
+    serde_path_to_error::deserialize(Entry::from(value))
+        .map_err(|error| (Path::from(error.path()), error.inner().to_string()))
+}
+
+impl From<Value> for Entry {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => Entry::Value(String::new()),
+            Value::String(inner) => Entry::Value(inner),
+            Value::Array(items) => Entry::Table(
+                items
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, item)| (index.to_string(), Entry::from(item)))
+                    .collect(),
+            ),
+            Value::Object(map) => Entry::Table(
+                map.into_iter()
+                    // Null settings are the same as missing settings
+                    .filter(|(_, item)| !item.is_null())
+                    .map(|(key, item)| (key, Entry::from(item)))
+                    .collect(),
+            ),
+            other => Entry::Value(other.to_string()),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Error(String);
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T: Display>(message: T) -> Self {
+        Error(message.to_string())
+    }
+}
+
+fn is_list(table: &IndexMap<String, Entry>) -> bool {
+    !table.is_empty() && table.keys().all(|key| key.parse::<usize>().is_ok())
+}
+
+fn into_list(table: IndexMap<String, Entry>) -> Vec<Entry> {
+    let mut items = table
+        .into_iter()
+        .map(|(key, entry)| (key.parse::<usize>().unwrap_or_default(), entry))
+        .collect::<Vec<_>>();
+
+    items.sort_by_key(|(index, _)| *index);
+    items.into_iter().map(|(_, entry)| entry).collect()
+}
+
+macro_rules! deserialize_parsed {
+    ($method:ident, $visit:ident, $ty:ty) => {
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            match self {
+                Entry::Value(value) => match value.trim().parse::<$ty>() {
+                    Ok(parsed) => visitor.$visit(parsed),
+                    Err(_) => Err(de::Error::invalid_value(Unexpected::Str(&value), &visitor)),
+                },
+                table => table.deserialize_any(visitor),
+            }
+        }
+    };
+}
+
+impl<'de> Deserializer<'de> for Entry {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Entry::Value(value) => visitor.visit_string(value),
+            Entry::Table(table) if is_list(&table) => visitor.visit_seq(EntrySeq {
+                items: into_list(table).into_iter(),
+            }),
+            Entry::Table(table) => visitor.visit_map(EntryMap {
+                entries: table.into_iter(),
+                value: None,
+            }),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Entry::Value(value) => match value.trim().to_lowercase().as_str() {
+                "true" | "yes" | "on" | "1" => visitor.visit_bool(true),
+                "false" | "no" | "off" | "0" => visitor.visit_bool(false),
+                _ => Err(de::Error::invalid_value(Unexpected::Str(&value), &visitor)),
+            },
+            table => table.deserialize_any(visitor),
+        }
+    }
+
+    deserialize_parsed!(deserialize_i8, visit_i8, i8);
+    deserialize_parsed!(deserialize_i16, visit_i16, i16);
+    deserialize_parsed!(deserialize_i32, visit_i32, i32);
+    deserialize_parsed!(deserialize_i64, visit_i64, i64);
+    deserialize_parsed!(deserialize_u8, visit_u8, u8);
+    deserialize_parsed!(deserialize_u16, visit_u16, u16);
+    deserialize_parsed!(deserialize_u32, visit_u32, u32);
+    deserialize_parsed!(deserialize_u64, visit_u64, u64);
+    deserialize_parsed!(deserialize_f32, visit_f32, f32);
+    deserialize_parsed!(deserialize_f64, visit_f64, f64);
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            // Keys without a value are the same as missing settings
+            Entry::Value(value) if value.is_empty() => visitor.visit_none(),
+            entry => visitor.visit_some(entry),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Entry::Value(value) if value.is_empty() => visitor.visit_unit(),
+            entry => entry.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            // Lists can also be written as comma separated values
+            Entry::Value(value) => visitor.visit_seq(EntrySeq {
+                items: value
+                    .split(',')
+                    .map(|item| item.trim())
+                    .filter(|item| !item.is_empty())
+                    .map(|item| Entry::Value(item.to_owned()))
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+            }),
+            Entry::Table(table) => visitor.visit_seq(EntrySeq {
+                items: into_list(table).into_iter(),
+            }),
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Entry::Table(table) => visitor.visit_map(EntryMap {
+                entries: table.into_iter(),
+                value: None,
+            }),
+            Entry::Value(value) => Err(de::Error::invalid_type(Unexpected::Str(&value), &visitor)),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self {
+            Entry::Value(value) => visitor.visit_enum(value.into_deserializer()),
+            table => table.deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 char str string bytes byte_buf unit_struct identifier ignored_any
+    }
+}
+
+struct EntrySeq {
+    items: std::vec::IntoIter<Entry>,
+}
+
+impl<'de> SeqAccess<'de> for EntrySeq {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        self.items
+            .next()
+            .map(|item| seed.deserialize(item))
+            .transpose()
+    }
+}
+
+struct EntryMap {
+    entries: indexmap::map::IntoIter<String, Entry>,
+    value: Option<Entry>,
+}
+
+impl<'de> MapAccess<'de> for EntryMap {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+
+                seed.deserialize(Entry::Value(key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        seed.deserialize(self.value.take().expect("Value requested before key."))
+    }
+}
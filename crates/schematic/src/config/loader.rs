@@ -1,32 +1,113 @@
 use crate::config::cacher::{BoxedCacher, Cacher, MemoryCache};
+use crate::config::environment::{track_env_vars, EnvProvider, ProcessEnv};
 use crate::config::errors::ConfigError;
 use crate::config::format::Format;
+use crate::config::format_parser::{with_loading_formats, FormatParser, FormatRegistry};
+use crate::config::includes::{
+    find_includes, get_include_key, remap_include_error, splice_include, Include,
+};
+#[cfg(feature = "schema")]
+use crate::config::inspect::{
+    find_deprecated_settings, find_unknown_settings, split_setting_values,
+};
+use crate::config::inspect::{
+    find_key_span, find_unchanged_settings, find_unknown_env_vars, find_value_span,
+};
+use crate::config::interpolate::Interpolator;
 use crate::config::layer::Layer;
-use crate::config::source::Source;
+use crate::config::profiles::Profiled;
+use crate::config::redact::is_redacting;
+#[cfg(feature = "schema")]
+use crate::config::setting_override::SettingOverride;
+use crate::config::source::{detect_format, Source};
+use crate::config::warnings::ConfigWarning;
+#[cfg(feature = "schema")]
+use crate::config::PROFILES_KEY;
 use crate::config::{Config, ExtendsFrom, PartialConfig};
+use schematic_types::{Schema, SchemaBuilder};
+use serde::de::DeserializeOwned;
+use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
+use serde_json::Value;
 use std::marker::PhantomData;
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
+use std::sync::{Mutex, OnceLock};
 use tracing::{instrument, trace};
 
 /// The result of loading a configuration. Includes the final configuration,
 /// and all layers that were loaded.
-#[derive(Serialize)]
 pub struct ConfigLoadResult<T: Config> {
     /// Final configuration, after all layers are merged.
     pub config: T,
 
     /// Partial layers, in order of declaration and extension.
     pub layers: Vec<Layer<T>>,
+
+    /// Non-fatal warnings that were encountered while loading.
+    pub warnings: Vec<ConfigWarning>,
+}
+
+impl<T: Config + Serialize> Serialize for ConfigLoadResult<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ConfigLoadResult", 2)?;
+
+        // Layers are redacted by the partial itself, but the final configuration
+        // is not generated by us, so redact it with the derived implementation
+        if is_redacting() {
+            let mut config =
+                serde_json::to_value(&self.config).map_err(serde::ser::Error::custom)?;
+
+            T::redact_settings(&mut config);
+
+            state.serialize_field("config", &config)?;
+        } else {
+            state.serialize_field("config", &self.config)?;
+        }
+
+        state.serialize_field("layers", &self.layers)?;
+        state.end()
+    }
+}
+
+// State that is shared across all layers while loading.
+struct LoadState<T: Config> {
+    // Merged result of all previous layers, for detecting redundant values.
+    merged: Option<T::Partial>,
+
+    // Whether the selected profile was found in any source.
+    profile_found: bool,
+}
+
+impl<T: Config> Default for LoadState<T> {
+    fn default() -> Self {
+        Self {
+            merged: None,
+            profile_found: false,
+        }
+    }
 }
 
 pub struct ConfigLoader<T: Config> {
     _config: PhantomData<T>,
+    args: Option<T::Partial>,
     cacher: Mutex<BoxedCacher>,
+    #[cfg(feature = "schema")]
+    collect_parse_errors: bool,
+    deny_duplicate_keys: bool,
+    env: Box<dyn EnvProvider>,
+    formats: FormatRegistry,
     help: Option<String>,
+    interpolator: Option<Interpolator>,
+    profile: Option<String>,
+    profile_env: Option<String>,
     sources: Vec<Source>,
     root: Option<PathBuf>,
+    resolve_includes: bool,
+    schema: OnceLock<Schema>,
+    #[cfg(feature = "schema")]
+    setting_overrides: Vec<SettingOverride>,
+    split_documents: bool,
+    warn_redundant_settings: bool,
 }
 
 impl<T: Config> ConfigLoader<T> {
@@ -35,13 +116,53 @@ impl<T: Config> ConfigLoader<T> {
     pub fn new() -> Self {
         ConfigLoader {
             _config: PhantomData,
+            args: None,
             cacher: Mutex::new(Box::<MemoryCache>::default()),
+            #[cfg(feature = "schema")]
+            collect_parse_errors: false,
+            deny_duplicate_keys: false,
+            env: Box::new(ProcessEnv),
+            formats: FormatRegistry::default(),
             help: None,
+            interpolator: None,
+            profile: None,
+            profile_env: None,
             sources: vec![],
             root: None,
+            resolve_includes: false,
+            schema: OnceLock::new(),
+            #[cfg(feature = "schema")]
+            setting_overrides: vec![],
+            split_documents: false,
+            warn_redundant_settings: false,
         }
     }
 
+    /// Parse the provided command line arguments (including the binary name, like
+    /// [`std::env::args`]) into a layer that will be applied after all sources and
+    /// environment variables. Arguments are derived from the configuration with
+    /// [`args::create_command`](crate::args::create_command).
+    #[cfg(feature = "args")]
+    pub fn args<I, S>(&mut self, args: I) -> Result<&mut Self, ConfigError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<std::ffi::OsString> + Clone,
+    {
+        self.args = Some(crate::args::parse_args::<T, I, S>(args)?);
+
+        Ok(self)
+    }
+
+    /// Convert the matches from a command created with
+    /// [`args::create_command`](crate::args::create_command) into a layer that
+    /// will be applied after all sources and environment variables.
+    #[cfg(feature = "args")]
+    pub fn arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<&mut Self, ConfigError> {
+        self.args = Some(crate::args::parse_matches::<T>(matches)?);
+
+        Ok(self)
+    }
+
     /// Add a code snippet source to load.
     pub fn code<S: TryInto<String>>(
         &mut self,
@@ -55,7 +176,20 @@ impl<T: Config> ConfigLoader<T> {
 
     /// Add a file source to load.
     pub fn file<S: TryInto<PathBuf>>(&mut self, path: S) -> Result<&mut Self, ConfigError> {
-        self.sources.push(Source::file(path, true)?);
+        self.sources.push(self.create_file_source(path, true)?);
+
+        Ok(self)
+    }
+
+    /// Add a file source to load with an explicit format, instead of detecting
+    /// the format from the file extension or content.
+    pub fn file_with_format<S: TryInto<PathBuf>>(
+        &mut self,
+        path: S,
+        format: Format,
+    ) -> Result<&mut Self, ConfigError> {
+        self.sources
+            .push(Source::file_with_format(path, true, format)?);
 
         Ok(self)
     }
@@ -65,7 +199,7 @@ impl<T: Config> ConfigLoader<T> {
         &mut self,
         path: S,
     ) -> Result<&mut Self, ConfigError> {
-        self.sources.push(Source::file(path, false)?);
+        self.sources.push(self.create_file_source(path, false)?);
 
         Ok(self)
     }
@@ -73,7 +207,23 @@ impl<T: Config> ConfigLoader<T> {
     /// Add a URL source to load.
     #[cfg(feature = "url")]
     pub fn url<S: TryInto<String>>(&mut self, url: S) -> Result<&mut Self, ConfigError> {
-        self.sources.push(Source::url(url)?);
+        let url: String = url.try_into().map_err(|_| ConfigError::InvalidUrl)?;
+        let format = detect_format(&url, &self.formats)?;
+
+        self.sources.push(Source::url_with_format(url, format)?);
+
+        Ok(self)
+    }
+
+    /// Add a URL source to load with an explicit format, instead of detecting
+    /// the format from the file extension, `Content-Type` header, or content.
+    #[cfg(feature = "url")]
+    pub fn url_with_format<S: TryInto<String>>(
+        &mut self,
+        url: S,
+        format: Format,
+    ) -> Result<&mut Self, ConfigError> {
+        self.sources.push(Source::url_with_format(url, format)?);
 
         Ok(self)
     }
@@ -95,24 +245,59 @@ impl<T: Config> ConfigLoader<T> {
     ) -> Result<ConfigLoadResult<T>, ConfigError> {
         trace!(config = T::META.name, "Loading configuration");
 
-        let layers = self.parse_into_layers(&self.sources, context)?;
-        let partial = self.merge_layers(&layers, context)?.finalize(context)?;
+        let mut layers = vec![];
+        let mut warnings = vec![];
+        let mut state = LoadState::default();
+
+        self.parse_into_layers(
+            &self.sources,
+            context,
+            &mut layers,
+            &mut state,
+            &mut warnings,
+        )?;
+
+        self.inspect_selected_profile(&state, &mut warnings);
+
+        let partial = self.merge_layers(&layers, context)?;
+
+        // Track which environment variables were used, so that we can inspect them
+        let (partial, used_env_vars) =
+            track_env_vars(|| partial.finalize_with_env(context, self.env.as_ref()));
+        let partial = self.apply_cli_layers(partial?, context)?;
+
+        self.inspect_deprecated_env_vars(&used_env_vars, &mut warnings);
+        self.inspect_unknown_env_vars(&used_env_vars, &mut warnings);
 
         // Validate the final result before moving on
-        partial
-            .validate(context, true)
-            .map_err(|error| ConfigError::Validator {
-                config: match layers.last() {
-                    Some(last) => self.get_location(&last.source).to_owned(),
-                    None => T::META.name.to_owned(),
-                },
-                error: Box::new(error),
-                help: self.help.clone(),
-            })?;
+        if let Err(error) = with_loading_formats(&self.formats, || partial.validate(context, true))
+        {
+            let config = match layers.last() {
+                Some(last) => self.get_location(&last.source).to_owned(),
+                None => T::META.name.to_owned(),
+            };
+            let (error, warning) = error.partition();
+
+            if let Some(warning) = warning {
+                warnings.push(ConfigWarning::Validator {
+                    config: config.clone(),
+                    error: Box::new(warning),
+                });
+            }
+
+            if let Some(error) = error {
+                return Err(ConfigError::Validator {
+                    config,
+                    error: Box::new(error),
+                    help: self.help.clone(),
+                });
+            }
+        }
 
         Ok(ConfigLoadResult {
             config: T::from_partial(partial),
             layers,
+            warnings,
         })
     }
 
@@ -128,18 +313,212 @@ impl<T: Config> ConfigLoader<T> {
     ) -> Result<T::Partial, ConfigError> {
         trace!(config = T::META.name, "Loading partial configuration");
 
-        let layers = self.parse_into_layers(&self.sources, context)?;
+        let mut layers = vec![];
+
+        self.parse_into_layers(
+            &self.sources,
+            context,
+            &mut layers,
+            &mut LoadState::default(),
+            &mut vec![],
+        )?;
+
         let partial = self.merge_layers(&layers, context)?;
 
         Ok(partial)
     }
 
+    /// Resolve a final configuration for the provided file path, by merging all
+    /// path overrides (declared with `#[setting(overrides)]`) that match the path,
+    /// in declaration order, on top of the previously loaded layers.
+    pub fn resolve_for_path<P: AsRef<Path>>(
+        &self,
+        result: &ConfigLoadResult<T>,
+        path: P,
+    ) -> Result<T, ConfigError> {
+        let context = <T::Partial as PartialConfig>::Context::default();
+
+        self.resolve_for_path_with_context(result, path, &context)
+    }
+
+    /// Resolve a final configuration for the provided file path with the provided
+    /// context. View [`ConfigLoader::resolve_for_path`] for more information.
+    #[instrument(name = "resolve_config_for_path", skip_all)]
+    pub fn resolve_for_path_with_context<P: AsRef<Path>>(
+        &self,
+        result: &ConfigLoadResult<T>,
+        path: P,
+        context: &<T::Partial as PartialConfig>::Context,
+    ) -> Result<T, ConfigError> {
+        let mut path = path.as_ref();
+
+        // Overrides are relative to the root, so strip it when available
+        if let Some(root) = &self.root {
+            if let Ok(rel_path) = path.strip_prefix(root) {
+                path = rel_path;
+            }
+        }
+
+        trace!(
+            config = T::META.name,
+            path = ?path,
+            "Resolving configuration for path"
+        );
+
+        let mut partial = self.merge_layers(&result.layers, context)?;
+
+        for path_override in partial.path_overrides() {
+            if path_override.matches(path) {
+                partial.merge(context, path_override.settings)?;
+            }
+        }
+
+        let partial = self.apply_cli_layers(
+            partial.finalize_with_env(context, self.env.as_ref())?,
+            context,
+        )?;
+
+        // Validate the resolved result, as overrides may have changed values
+        if let Err(error) = partial.validate(context, true) {
+            if let (Some(error), _) = error.partition() {
+                return Err(ConfigError::Validator {
+                    config: T::META.name.to_owned(),
+                    error: Box::new(error),
+                    help: self.help.clone(),
+                });
+            }
+        }
+
+        Ok(T::from_partial(partial))
+    }
+
+    /// Collect the type errors of all settings within a source, instead of failing
+    /// on the first invalid setting. When multiple settings are invalid, loading will
+    /// fail with a [`ConfigError::Parsers`] error that contains an error for each.
+    /// Requires the `schema` Cargo feature, as settings are split using the schema.
+    #[cfg(feature = "schema")]
+    pub fn collect_parse_errors(&mut self) -> &mut Self {
+        self.collect_parse_errors = true;
+        self
+    }
+
+    /// Fail when a key is defined more than once within the same map of a source,
+    /// at any depth, instead of silently keeping the last value. Applies to all
+    /// sources, including extended and included sources.
+    pub fn deny_duplicate_keys(&mut self) -> &mut Self {
+        self.deny_duplicate_keys = true;
+        self
+    }
+
+    /// Enable variable interpolation (`${VAR}`) within string values, using the
+    /// default [`Interpolator`], which resolves environment variables and files.
+    pub fn interpolate(&mut self) -> &mut Self {
+        self.interpolator = Some(Interpolator::default());
+        self
+    }
+
+    /// Enable include directives, which replace an object containing an
+    /// [`INCLUDE_KEY`](crate::INCLUDE_KEY) (`{ "$include": "path" }`), or a YAML
+    /// `!include path` tag, with the contents of another file or URL. Paths are
+    /// relative to the source that contains the include.
+    pub fn resolve_includes(&mut self) -> &mut Self {
+        self.resolve_includes = true;
+        self
+    }
+
+    /// Select a named profile to merge on top of the base settings of each source.
+    /// Requires the `#[config(profiles)]` container attribute.
+    pub fn profile<P: AsRef<str>>(&mut self, name: P) -> &mut Self {
+        self.profile = Some(name.as_ref().to_owned());
+        self
+    }
+
+    /// Select a named profile from the provided environment variable. If the
+    /// variable is set, it takes precedence over [`ConfigLoader::profile`].
+    pub fn profile_from_env<K: AsRef<str>>(&mut self, key: K) -> &mut Self {
+        self.profile_env = Some(key.as_ref().to_owned());
+        self
+    }
+
+    /// Override the value of a setting at the provided dot separated path (`server.port`),
+    /// including map keys and list indices (`list[0]`). The value is parsed into the
+    /// setting's type, and applied after all sources, environment variables, and arguments.
+    /// Requires the `schema` Cargo feature, as paths are resolved against the schema.
+    #[cfg(feature = "schema")]
+    pub fn set_override<P: AsRef<str>, V: AsRef<str>>(
+        &mut self,
+        path: P,
+        value: V,
+    ) -> Result<&mut Self, ConfigError> {
+        let setting = SettingOverride::new(self.get_schema(), path.as_ref(), value.as_ref())?;
+
+        self.setting_overrides.push(setting);
+
+        Ok(self)
+    }
+
+    /// Override the value of multiple settings, with a list of `path=value` pairs.
+    /// See [`ConfigLoader::set_override`] for more information.
+    #[cfg(feature = "schema")]
+    pub fn overrides<I, V>(&mut self, pairs: I) -> Result<&mut Self, ConfigError>
+    where
+        I: IntoIterator<Item = V>,
+        V: AsRef<str>,
+    {
+        for pair in pairs {
+            let setting = SettingOverride::parse(self.get_schema(), pair.as_ref())?;
+
+            self.setting_overrides.push(setting);
+        }
+
+        Ok(self)
+    }
+
+    /// Register a custom format parser, so that sources with its file extensions
+    /// can be loaded, extended, and included. Formats must be registered before
+    /// adding the sources that use them.
+    pub fn register_format(&mut self, parser: impl FormatParser + 'static) -> &mut Self {
+        self.formats.register(parser);
+        self
+    }
+
+    /// Treat each document within a multi-document source (YAML only) as its
+    /// own layer, merged in order, instead of failing to parse the source.
+    pub fn split_documents(&mut self) -> &mut Self {
+        self.split_documents = true;
+        self
+    }
+
+    /// Emit a [`ConfigWarning::RedundantSetting`] for each setting that is equal to its
+    /// default value, or the value inherited from previous layers. This compares the
+    /// merged state before and after each layer, so is disabled by default.
+    pub fn warn_redundant_settings(&mut self) -> &mut Self {
+        self.warn_redundant_settings = true;
+        self
+    }
+
     /// Set a cacher instance that'll read and write the cache for URL requests.
     pub fn set_cacher(&mut self, cacher: impl Cacher + 'static) -> &mut Self {
         self.cacher = Mutex::new(Box::new(cacher));
         self
     }
 
+    /// Set a custom [`EnvProvider`] that'll be used to read environment variables for
+    /// settings marked with `#[setting(env)]`, deprecated variables, interpolated
+    /// variables, and the profile from [`ConfigLoader::profile_from_env`].
+    /// Defaults to the current process.
+    pub fn set_env_provider(&mut self, env: impl EnvProvider + 'static) -> &mut Self {
+        self.env = Box::new(env);
+        self
+    }
+
+    /// Set a custom [`Interpolator`] instance, with additional resolvers, that'll
+    /// interpolate variables within string values.
+    pub fn set_interpolator(&mut self, interpolator: Interpolator) -> &mut Self {
+        self.interpolator = Some(interpolator);
+        self
+    }
+
     /// Set a string of help text to include in validation errors.
     pub fn set_help<H: AsRef<str>>(&mut self, help: H) -> &mut Self {
         self.help = Some(help.as_ref().to_owned());
@@ -158,11 +537,14 @@ impl<T: Config> ConfigLoader<T> {
         context: &<T::Partial as PartialConfig>::Context,
         parent_source: &Source,
         extends_from: &ExtendsFrom,
-    ) -> Result<Vec<Layer<T>>, ConfigError> {
+        layers: &mut Vec<Layer<T>>,
+        state: &mut LoadState<T>,
+        warnings: &mut Vec<ConfigWarning>,
+    ) -> Result<(), ConfigError> {
         let mut sources = vec![];
 
         let mut extend_source = |value: &str| {
-            let source = Source::new(value, Some(parent_source))?;
+            let source = Source::new_with_formats(value, Some(parent_source), &self.formats)?;
 
             // Extending from code is not possible
             if matches!(source, Source::Code { .. }) {
@@ -191,7 +573,75 @@ impl<T: Config> ConfigLoader<T> {
             }
         };
 
-        self.parse_into_layers(&sources, context)
+        self.parse_into_layers(&sources, context, layers, state, warnings)
+    }
+
+    fn apply_cli_layers(
+        &self,
+        mut partial: T::Partial,
+        context: &<T::Partial as PartialConfig>::Context,
+    ) -> Result<T::Partial, ConfigError> {
+        if let Some(args) = &self.args {
+            partial.merge(context, args.clone())?;
+        }
+
+        #[cfg(feature = "schema")]
+        if !self.setting_overrides.is_empty() {
+            return self.apply_setting_overrides(partial);
+        }
+
+        Ok(partial)
+    }
+
+    #[cfg(feature = "schema")]
+    fn apply_setting_overrides(&self, partial: T::Partial) -> Result<T::Partial, ConfigError> {
+        let mut value = serde_json::to_value(&partial).map_err(|error| {
+            ConfigError::Handler(Box::new(crate::config::HandlerError::new(
+                error.to_string(),
+            )))
+        })?;
+
+        for setting in &self.setting_overrides {
+            setting.apply(&mut value)?;
+        }
+
+        serde_path_to_error::deserialize(value).map_err(|error| ConfigError::InvalidOverrideValue {
+            path: crate::config::Path::from(error.path()).to_string(),
+            message: error.inner().to_string(),
+        })
+    }
+
+    fn create_file_source<S: TryInto<PathBuf>>(
+        &self,
+        path: S,
+        required: bool,
+    ) -> Result<Source, ConfigError> {
+        let path: PathBuf = path.try_into().map_err(|_| ConfigError::InvalidFile)?;
+        let format = detect_format(path.to_str().unwrap_or_default(), &self.formats)?;
+
+        Source::file_with_format(path, required, format)
+    }
+
+    fn get_schema(&self) -> &Schema {
+        self.schema.get_or_init(SchemaBuilder::build_root::<T>)
+    }
+
+    // Deprecated and unknown settings are inspected using the schema, while other
+    // features (includes, interpolation, profiles, etc) operate on the generic value.
+    fn requires_value(&self) -> bool {
+        cfg!(feature = "schema")
+            || T::META.profiles
+            || self.interpolator.is_some()
+            || self.resolve_includes
+            || self.warn_redundant_settings
+    }
+
+    fn get_profile(&self) -> Option<String> {
+        self.profile_env
+            .as_ref()
+            .and_then(|key| self.env.get(key))
+            .filter(|value| !value.is_empty())
+            .or_else(|| self.profile.clone())
     }
 
     fn get_location<'l>(&self, source: &'l Source) -> &'l str {
@@ -214,6 +664,456 @@ impl<T: Config> ConfigLoader<T> {
         }
     }
 
+    #[cfg(feature = "schema")]
+    fn inspect_deprecated_settings(
+        &self,
+        schema: &Schema,
+        value: &Value,
+        content: &str,
+        location: &str,
+        warnings: &mut Vec<ConfigWarning>,
+    ) {
+        for (path, message) in find_deprecated_settings(schema, value) {
+            warnings.push(ConfigWarning::DeprecatedSetting {
+                config: location.to_owned(),
+                content: content.to_owned(),
+                message,
+                span: find_key_span(content, &path),
+                path: path.to_string(),
+            });
+        }
+    }
+
+    fn interpolate_variables(
+        &self,
+        schema: &Schema,
+        source: &Source,
+        value: &mut Value,
+        content: &str,
+        location: &str,
+    ) -> Result<bool, ConfigError> {
+        let Some(interpolator) = &self.interpolator else {
+            return Ok(false);
+        };
+
+        interpolator
+            .interpolate_value(
+                Some(schema),
+                value,
+                source,
+                self.env.as_ref(),
+                Default::default(),
+            )
+            .map_err(|error| ConfigError::UnresolvedVariable {
+                config: location.to_owned(),
+                content: content.to_owned(),
+                span: find_value_span(content, &error.path, &error.variable),
+                name: error.variable,
+                path: error.path.to_string(),
+                help: error.message,
+            })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn include_sources(
+        &self,
+        parent_source: &Source,
+        value: &mut Value,
+        content: &str,
+        location: &str,
+        parent_path: &crate::config::Path,
+        stack: &mut Vec<String>,
+        includes: &mut Vec<Include>,
+        warnings: &mut Vec<ConfigWarning>,
+    ) -> Result<(), ConfigError> {
+        for (path, include) in find_includes(value) {
+            let setting_path = parent_path.join_path(&path);
+            let span = find_value_span(content, &path, &include);
+
+            let create_error = |error: ConfigError| ConfigError::IncludeFailed {
+                config: location.to_owned(),
+                content: content.to_owned(),
+                include: include.clone(),
+                path: setting_path.to_string(),
+                span,
+                error: Box::new(error),
+            };
+
+            let mut source = Source::new_with_formats(&include, Some(parent_source), &self.formats)
+                .map_err(create_error)?;
+            let key = get_include_key(&source);
+
+            if stack.contains(&key) {
+                return Err(ConfigError::IncludeCycle {
+                    config: location.to_owned(),
+                    content: content.to_owned(),
+                    include,
+                    path: setting_path.to_string(),
+                    span,
+                });
+            }
+
+            trace!(
+                config = T::META.name,
+                source = source.as_str(),
+                path = setting_path.to_string(),
+                "Including source"
+            );
+
+            let (included_content, format) =
+                self.read_source(&source, warnings).map_err(create_error)?;
+
+            source.set_format(format);
+
+            let included_location = self.get_location(&source).to_owned();
+
+            self.check_duplicate_keys(&source, &included_content, &included_location)
+                .map_err(create_error)?;
+
+            let mut included = source
+                .parse_content_with_formats::<Value>(
+                    included_content.clone(),
+                    &included_location,
+                    self.help.as_deref(),
+                    &self.formats,
+                )
+                .map_err(create_error)?;
+
+            // Includes within the included source are relative to it
+            stack.push(key);
+
+            self.include_sources(
+                &source,
+                &mut included,
+                &included_content,
+                &included_location,
+                &setting_path,
+                stack,
+                includes,
+                warnings,
+            )?;
+
+            stack.pop();
+
+            splice_include(value, &path, included).map_err(create_error)?;
+
+            includes.push(Include {
+                path: setting_path,
+                location: included_location,
+                content: included_content,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn check_duplicate_keys(
+        &self,
+        source: &Source,
+        content: &str,
+        location: &str,
+    ) -> Result<(), ConfigError> {
+        if !self.deny_duplicate_keys {
+            return Ok(());
+        }
+
+        source
+            .get_format()
+            .check_duplicate_keys_with_formats(content, &self.formats)
+            .map_err(|error| ConfigError::Parser {
+                config: location.to_owned(),
+                error,
+                help: self.help.clone(),
+            })
+    }
+
+    // Deserialize each setting independently, so that all invalid settings are found,
+    // but only return an error when there are multiple, as a single error is clearer
+    #[cfg(feature = "schema")]
+    fn collect_setting_errors(
+        &self,
+        schema: &Schema,
+        source: &Source,
+        value: &Value,
+        content: &str,
+        location: &str,
+        includes: &[Include],
+    ) -> Option<ConfigError> {
+        let mut value = value.clone();
+
+        // Profiles are parsed separately from the base settings
+        if let Value::Object(map) = &mut value {
+            if T::META.profiles {
+                map.remove(PROFILES_KEY);
+            }
+        }
+
+        let errors = split_setting_values(schema, &value)
+            .into_iter()
+            .filter_map(|setting| {
+                let error = source
+                    .parse_value::<T::Partial>(content, setting, location, self.help.as_deref())
+                    .err()?;
+
+                match remap_include_error(error, includes) {
+                    ConfigError::Parser { error, .. } => Some(error),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if errors.len() < 2 {
+            return None;
+        }
+
+        Some(ConfigError::Parsers {
+            config: location.to_owned(),
+            errors,
+            help: self.help.clone(),
+        })
+    }
+
+    fn inspect_deprecated_env_vars(&self, used: &[String], warnings: &mut Vec<ConfigWarning>) {
+        let settings = T::env_vars();
+
+        for name in used {
+            let Some(setting) = settings
+                .iter()
+                .find(|setting| setting.deprecated.contains(&name.as_str()))
+            else {
+                continue;
+            };
+
+            let replacement = setting
+                .names
+                .iter()
+                .find(|key| !setting.deprecated.contains(key));
+
+            warnings.push(ConfigWarning::DeprecatedEnvVar {
+                name: name.to_owned(),
+                path: setting.path.to_string(),
+                help: replacement.map(|key| format!("Use {key} instead.")),
+            });
+        }
+    }
+
+    fn inspect_unknown_env_vars(&self, used: &[String], warnings: &mut Vec<ConfigWarning>) {
+        let Some(prefix) = T::META.env_prefix else {
+            return;
+        };
+
+        let settings = T::env_vars();
+        let known = settings
+            .iter()
+            .flat_map(|setting| setting.names.iter().chain(&setting.deprecated).copied())
+            .chain(used.iter().map(|name| name.as_str()))
+            .collect::<Vec<_>>();
+        let ignored = self.profile_env.as_deref().into_iter().collect::<Vec<_>>();
+
+        for (name, suggestion) in find_unknown_env_vars(
+            &known,
+            self.env.as_ref(),
+            prefix,
+            T::META.env_file_suffix,
+            &ignored,
+        ) {
+            warnings.push(ConfigWarning::UnknownEnvVar {
+                name,
+                help: suggestion.map(|key| format!("Did you mean `{key}`?")),
+            });
+        }
+    }
+
+    fn inspect_selected_profile(&self, state: &LoadState<T>, warnings: &mut Vec<ConfigWarning>) {
+        if !T::META.profiles || state.profile_found {
+            return;
+        }
+
+        if let Some(name) = self.get_profile() {
+            warnings.push(ConfigWarning::UnknownProfile {
+                name,
+                config: T::META.name.to_owned(),
+            });
+        }
+    }
+
+    #[cfg(feature = "schema")]
+    fn inspect_unknown_settings(
+        &self,
+        schema: &Schema,
+        value: &Value,
+        content: &str,
+        location: &str,
+        warnings: &mut Vec<ConfigWarning>,
+    ) -> Result<(), ConfigError> {
+        for unknown in find_unknown_settings(schema, value) {
+            let help = unknown.get_help();
+            let span = find_key_span(content, &unknown.path);
+            let path = unknown.path.to_string();
+
+            // Fail on the first unknown setting, as serde would,
+            // but with a more useful error message
+            if unknown.denied {
+                return Err(ConfigError::UnknownSetting {
+                    config: location.to_owned(),
+                    content: content.to_owned(),
+                    path,
+                    span,
+                    help,
+                });
+            }
+
+            warnings.push(ConfigWarning::UnknownSetting {
+                config: location.to_owned(),
+                content: content.to_owned(),
+                path,
+                span,
+                help,
+            });
+        }
+
+        Ok(())
+    }
+
+    // Compare the merged state of all previous layers (and defaults) against the
+    // state after this layer is merged in, and then advance the merged state.
+    // This is a warning only, so failures must never abort loading.
+    fn inspect_redundant_values(
+        &self,
+        value: Option<&Value>,
+        content: &str,
+        location: &str,
+        context: &<T::Partial as PartialConfig>::Context,
+        merged: &mut Option<T::Partial>,
+        partial: &T::Partial,
+    ) -> Vec<ConfigWarning> {
+        let current = match merged {
+            Some(current) => current,
+            None => match T::Partial::default_values(context) {
+                Ok(defaults) => merged.insert(defaults.unwrap_or_default()),
+                Err(_) => return vec![],
+            },
+        };
+
+        let mut next = current.clone();
+
+        if next.merge(context, partial.clone()).is_err() {
+            return vec![];
+        }
+
+        let previous = std::mem::replace(current, next);
+
+        let Some(value) = value else {
+            return vec![];
+        };
+
+        let (Ok(previous), Ok(next)) = (
+            serde_json::to_value(previous),
+            serde_json::to_value(&*current),
+        ) else {
+            return vec![];
+        };
+
+        find_unchanged_settings(&previous, &next, value)
+            .into_iter()
+            .map(|path| ConfigWarning::RedundantSetting {
+                config: location.to_owned(),
+                content: content.to_owned(),
+                span: find_key_span(content, &path),
+                path: path.to_string(),
+            })
+            .collect()
+    }
+
+    #[cfg_attr(not(feature = "url"), allow(unused_variables, clippy::ptr_arg))]
+    fn read_source(
+        &self,
+        source: &Source,
+        warnings: &mut Vec<ConfigWarning>,
+    ) -> Result<(String, Format), ConfigError> {
+        let mut cacher = self.cacher.lock().unwrap();
+
+        match source.read_with_format(&mut cacher) {
+            Ok(result) => Ok(result),
+
+            // If the URL failed to load, fallback to a stale cache if available
+            #[cfg(feature = "url")]
+            Err(ConfigError::ReadUrlFailed { url, error }) => match cacher.read_stale(&url)? {
+                Some(content) => {
+                    warnings.push(ConfigWarning::StaleCache {
+                        url,
+                        error: error.to_string(),
+                    });
+
+                    let format = source.resolve_format(&content, None)?;
+
+                    Ok((content, format))
+                }
+                None => Err(ConfigError::ReadUrlFailed { url, error }),
+            },
+
+            Err(error) => Err(error),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn parse_partial(
+        &self,
+        source: &Source,
+        content: &str,
+        value: Option<&Value>,
+        modified: bool,
+        location: &str,
+        context: &<T::Partial as PartialConfig>::Context,
+        profile_found: &mut bool,
+    ) -> Result<T::Partial, ConfigError> {
+        if !T::META.profiles {
+            return self.parse_document(source, content, value, modified, location);
+        }
+
+        // Parse the base settings and all profiles, even if not selected,
+        // so that we can catch errors as early as possible
+        let Profiled {
+            base: mut partial,
+            mut profiles,
+        } = self
+            .parse_document::<Profiled<T::Partial>>(source, content, value, modified, location)?;
+
+        if let Some(name) = self.get_profile() {
+            if let Some(profile) = profiles.remove(&name) {
+                trace!(
+                    config = T::META.name,
+                    profile = &name,
+                    "Merging selected profile"
+                );
+
+                partial.merge(context, profile)?;
+                *profile_found = true;
+            }
+        }
+
+        Ok(partial)
+    }
+
+    fn parse_document<D: DeserializeOwned>(
+        &self,
+        source: &Source,
+        content: &str,
+        value: Option<&Value>,
+        modified: bool,
+        location: &str,
+    ) -> Result<D, ConfigError> {
+        let help = self.help.as_deref();
+
+        // If the value was modified, we must parse from it instead of the content
+        match value.filter(|_| modified) {
+            Some(value) => source.parse_value(content, value.clone(), location, help),
+            None => {
+                source.parse_content_with_formats(content.to_owned(), location, help, &self.formats)
+            }
+        }
+    }
+
     #[instrument(skip_all)]
     fn merge_layers(
         &self,
@@ -241,9 +1141,10 @@ impl<T: Config> ConfigLoader<T> {
         &self,
         sources_to_parse: &[Source],
         context: &<T::Partial as PartialConfig>::Context,
-    ) -> Result<Vec<Layer<T>>, ConfigError> {
-        let mut layers: Vec<Layer<T>> = vec![];
-
+        layers: &mut Vec<Layer<T>>,
+        state: &mut LoadState<T>,
+        warnings: &mut Vec<ConfigWarning>,
+    ) -> Result<(), ConfigError> {
         for source in sources_to_parse {
             trace!(
                 config = T::META.name,
@@ -251,35 +1152,146 @@ impl<T: Config> ConfigLoader<T> {
                 "Creating layer from source"
             );
 
-            // Determine the source location for use in error messages
-            let location = self.get_location(source);
+            // Read the source, and detect its format if it wasn't inferred
+            // from a file extension, so that the layer retains the format
+            let (content, format) = self.read_source(source, warnings)?;
+            let mut source = source.clone();
 
-            // Parse the source into a parial
-            let partial: T::Partial = {
-                let mut cacher = self.cacher.lock().unwrap();
+            source.set_format(format);
 
-                source.parse(location, &mut cacher, self.help.as_deref())?
+            // Each document within a multi-document source is its own layer
+            let documents = if self.split_documents {
+                format.split_documents(&content)
+            } else {
+                vec![content]
             };
 
-            // Validate before continuing so we ensure the values are correct
-            partial
-                .validate(context, false)
-                .map_err(|error| ConfigError::Validator {
+            for content in documents {
+                self.parse_into_layer(&source, content, context, layers, state, warnings)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_into_layer(
+        &self,
+        source: &Source,
+        content: String,
+        context: &<T::Partial as PartialConfig>::Context,
+        layers: &mut Vec<Layer<T>>,
+        state: &mut LoadState<T>,
+        warnings: &mut Vec<ConfigWarning>,
+    ) -> Result<(), ConfigError> {
+        let schema = self.get_schema();
+
+        // Determine the source location for use in error messages
+        let location = self.get_location(source);
+
+        self.check_duplicate_keys(source, &content, location)?;
+
+        // Parse the source into a generic value for inspection, but only when required,
+        // as the content is parsed a second time. If the content can't be converted into
+        // a generic value, then parsing into a partial will also fail, and will provide
+        // a better error.
+        let mut value = if self.requires_value() {
+            source
+                .parse_content_with_formats::<Value>(
+                    content.clone(),
+                    location,
+                    self.help.as_deref(),
+                    &self.formats,
+                )
+                .ok()
+        } else {
+            None
+        };
+        let mut modified = false;
+        let mut includes = vec![];
+
+        if let Some(value) = &mut value {
+            if self.resolve_includes {
+                self.include_sources(
+                    source,
+                    value,
+                    &content,
+                    location,
+                    &crate::config::Path::default(),
+                    &mut vec![get_include_key(source)],
+                    &mut includes,
+                    warnings,
+                )?;
+            }
+
+            #[cfg(feature = "schema")]
+            self.inspect_unknown_settings(schema, value, &content, location, warnings)?;
+
+            modified = self.interpolate_variables(schema, source, value, &content, location)?
+                || !includes.is_empty();
+        }
+
+        // Then parse it into a partial, with the selected profile merged in
+        let partial = self
+            .parse_partial(
+                source,
+                &content,
+                value.as_ref(),
+                modified,
+                location,
+                context,
+                &mut state.profile_found,
+            )
+            .map_err(|error| {
+                #[cfg(feature = "schema")]
+                if let Some(value) = value.as_ref().filter(|_| self.collect_parse_errors) {
+                    return self
+                        .collect_setting_errors(
+                            schema, source, value, &content, location, &includes,
+                        )
+                        .unwrap_or_else(|| remap_include_error(error, &includes));
+                }
+
+                remap_include_error(error, &includes)
+            })?;
+
+        #[cfg(feature = "schema")]
+        if let Some(value) = &value {
+            self.inspect_deprecated_settings(schema, value, &content, location, warnings);
+        }
+
+        // Validate before continuing so we ensure the values are correct,
+        // but ignore warnings, as they'll be reported when finalizing
+        if let Err(error) = with_loading_formats(&self.formats, || partial.validate(context, false))
+        {
+            if let (Some(error), _) = error.partition() {
+                return Err(ConfigError::Validator {
                     config: location.to_owned(),
                     error: Box::new(error),
                     help: self.help.clone(),
-                })?;
-
-            if let Some(extends_from) = partial.extends_from() {
-                layers.extend(self.extend_additional_layers(context, source, &extends_from)?);
+                });
             }
+        }
 
-            layers.push(Layer {
-                partial,
-                source: source.clone(),
-            });
+        if let Some(extends_from) = partial.extends_from() {
+            self.extend_additional_layers(context, source, &extends_from, layers, state, warnings)?;
         }
 
-        Ok(layers)
+        if self.warn_redundant_settings {
+            warnings.extend(self.inspect_redundant_values(
+                value.as_ref(),
+                &content,
+                location,
+                context,
+                &mut state.merged,
+                &partial,
+            ));
+        }
+
+        layers.push(Layer {
+            partial,
+            source: source.clone(),
+        });
+
+        Ok(())
     }
 }
@@ -1,23 +1,49 @@
 mod cacher;
 mod configs;
+#[cfg(any(feature = "json", feature = "json5", feature = "ron", feature = "yaml"))]
+mod duplicates;
+mod environment;
 mod errors;
 mod format;
+mod format_parser;
+#[cfg(feature = "hcl")]
+mod hcl;
+mod includes;
+pub(crate) mod inspect;
+mod interpolate;
+#[cfg(any(feature = "ini", feature = "properties"))]
+mod key_value;
 mod layer;
 mod loader;
+mod overrides;
 mod parser;
 mod path;
+mod profiles;
+mod redact;
+#[cfg(feature = "schema")]
+mod setting_override;
 mod source;
 mod validator;
+mod warnings;
 
 pub use cacher::*;
 pub use configs::*;
+pub use environment::*;
 pub use errors::*;
+pub use format_parser::*;
+pub use includes::INCLUDE_KEY;
+pub use interpolate::*;
 pub use layer::*;
 pub use loader::*;
+pub use overrides::*;
 pub use parser::*;
 pub use path::*;
+pub use redact::*;
+#[cfg(feature = "schema")]
+pub use setting_override::*;
 pub use source::*;
 pub use validator::*;
+pub use warnings::*;
 
 #[macro_export]
 macro_rules! derive_enum {
@@ -0,0 +1,136 @@
+use crate::config::errors::ConfigError;
+use glob::{MatchOptions, Pattern};
+use schematic_types::Schematic;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Represents a block of settings that only apply to file paths that match
+/// one of the provided glob patterns. Is declared with `#[setting(overrides)]`.
+///
+/// Patterns are compiled when the override is created or deserialized, so
+/// that invalid patterns fail while loading, instead of while resolving.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(try_from = "RawPathOverride<T>")]
+pub struct PathOverride<T> {
+    /// List of glob patterns to match file paths against. Patterns without
+    /// a path separator will be matched against the file name only.
+    files: Vec<String>,
+
+    /// Partial settings to merge when a file path matches.
+    pub settings: T,
+
+    #[serde(skip)]
+    patterns: Vec<Pattern>,
+}
+
+fn compile_patterns(files: &[String]) -> Result<Vec<Pattern>, ConfigError> {
+    files
+        .iter()
+        .map(|file| {
+            Pattern::new(file.trim_start_matches("./")).map_err(|error| {
+                ConfigError::InvalidOverridePattern {
+                    pattern: file.to_owned(),
+                    error: Box::new(error),
+                }
+            })
+        })
+        .collect()
+}
+
+impl<T> PathOverride<T> {
+    /// Create a new path override, and compile the provided glob patterns.
+    pub fn new<I, V>(files: I, settings: T) -> Result<Self, ConfigError>
+    where
+        I: IntoIterator<Item = V>,
+        V: AsRef<str>,
+    {
+        let files = files
+            .into_iter()
+            .map(|file| file.as_ref().to_owned())
+            .collect::<Vec<_>>();
+
+        Ok(Self {
+            patterns: compile_patterns(&files)?,
+            files,
+            settings,
+        })
+    }
+
+    /// Return the list of glob patterns to match file paths against.
+    pub fn files(&self) -> &[String] {
+        &self.files
+    }
+
+    /// Replace the list of glob patterns, and compile them. If a pattern
+    /// is invalid, the existing patterns are left unchanged.
+    pub fn set_files<I, V>(&mut self, files: I) -> Result<(), ConfigError>
+    where
+        I: IntoIterator<Item = V>,
+        V: AsRef<str>,
+    {
+        let files = files
+            .into_iter()
+            .map(|file| file.as_ref().to_owned())
+            .collect::<Vec<_>>();
+
+        self.patterns = compile_patterns(&files)?;
+        self.files = files;
+
+        Ok(())
+    }
+
+    /// Return true if the provided path matches any of the glob patterns.
+    /// The path should be relative, and use forward slashes as separators.
+    pub fn matches(&self, path: &Path) -> bool {
+        let options = MatchOptions {
+            case_sensitive: true,
+            require_literal_separator: true,
+            require_literal_leading_dot: false,
+        };
+
+        let path = path.to_string_lossy().replace('\\', "/");
+        let path = path.trim_start_matches("./");
+        let file_name = path.rsplit('/').next().unwrap_or(path);
+
+        self.files
+            .iter()
+            .zip(&self.patterns)
+            .any(|(file, pattern)| {
+                let target = if file.contains('/') { path } else { file_name };
+
+                pattern.matches_with(target, options)
+            })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawPathOverride<T> {
+    files: Vec<String>,
+    settings: T,
+}
+
+impl<T> TryFrom<RawPathOverride<T>> for PathOverride<T> {
+    type Error = String;
+
+    fn try_from(raw: RawPathOverride<T>) -> Result<Self, Self::Error> {
+        Self::new(raw.files, raw.settings).map_err(|error| error.to_full_string())
+    }
+}
+
+impl<T: Schematic> Schematic for PathOverride<T> {
+    fn build_schema(mut schema: schematic_types::SchemaBuilder) -> schematic_types::Schema {
+        use schematic_types::*;
+
+        let mut files = schema.infer::<Vec<String>>();
+        files.description = Some("List of glob patterns to match file paths against.".into());
+
+        let mut settings = schema.infer::<T>();
+        settings.description = Some("Settings to apply when a file path matches.".into());
+
+        schema.structure(StructType {
+            deny_unknown_fields: true,
+            ..StructType::new([("files".into(), files), ("settings".into(), settings)])
+        })
+    }
+}
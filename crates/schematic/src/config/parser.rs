@@ -33,4 +33,7 @@ pub struct ParserError {
 
     #[label("Fix this")]
     pub span: Option<SourceSpan>,
+
+    #[label("First defined here")]
+    pub first_span: Option<SourceSpan>,
 }
@@ -1,7 +1,7 @@
 use std::fmt::{self, Display};
 
 /// Represents all the different forms a path is composed of.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PathSegment {
     /// List index: `[0]`
     Index(usize),
@@ -14,7 +14,7 @@ pub enum PathSegment {
 }
 
 /// Represents the path from the struct root to nested a field or field value.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Path {
     /// List of path segments.
     segments: Vec<PathSegment>,
@@ -26,6 +26,43 @@ impl Path {
         Self { segments }
     }
 
+    /// Parse a dot separated path, with optional list indices, like `parent.child[0]`,
+    /// into a new instance. Returns [`None`] if the path is empty or malformed.
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut segments = vec![];
+
+        for part in value.split('.') {
+            let (key, mut rest) = match part.find('[') {
+                Some(index) => part.split_at(index),
+                None => (part, ""),
+            };
+
+            if key.is_empty() {
+                return None;
+            }
+
+            segments.push(PathSegment::Key(key.to_owned()));
+
+            while !rest.is_empty() {
+                let end = rest.find(']')?;
+
+                segments.push(PathSegment::Index(rest[1..end].parse().ok()?));
+                rest = &rest[end + 1..];
+
+                if !rest.is_empty() && !rest.starts_with('[') {
+                    return None;
+                }
+            }
+        }
+
+        Some(Self { segments })
+    }
+
+    /// Return a list of all [`PathSegment`]s in the path.
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
     /// Create a new instance and append the provided [`PathSegment`]
     /// to the end of the current path.
     pub fn join(&self, segment: PathSegment) -> Self {
@@ -54,6 +91,14 @@ impl Path {
         path
     }
 
+    /// Create a new instance with the provided prefix removed from the start
+    /// of the current path. Returns [`None`] if the path does not start with the prefix.
+    pub fn strip_prefix(&self, prefix: &Self) -> Option<Self> {
+        self.segments
+            .strip_prefix(prefix.segments.as_slice())
+            .map(|segments| Self::new(segments.to_vec()))
+    }
+
     /// Create a new instance and append an `Variant` [`PathSegment`]
     /// to the end of the current path.
     pub fn join_variant(&self, variant: &str) -> Self {
@@ -61,6 +106,23 @@ impl Path {
     }
 }
 
+impl From<&serde_path_to_error::Path> for Path {
+    fn from(path: &serde_path_to_error::Path) -> Self {
+        use serde_path_to_error::Segment;
+
+        Self::new(
+            path.iter()
+                .map(|segment| match segment {
+                    Segment::Seq { index } => PathSegment::Index(*index),
+                    Segment::Map { key } => PathSegment::Key(key.to_owned()),
+                    Segment::Enum { variant } => PathSegment::Variant(variant.to_owned()),
+                    Segment::Unknown => PathSegment::Unknown,
+                })
+                .collect(),
+        )
+    }
+}
+
 impl Display for Path {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         if self.segments.is_empty() {
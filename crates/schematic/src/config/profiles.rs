@@ -0,0 +1,103 @@
+use crate::config::PROFILES_KEY;
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{self, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, Visitor};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Base settings and their named profiles, deserialized in a single pass
+/// directly from the source's format, so that format specific types
+/// (TOML datetimes, RON enums, etc) and spans are preserved.
+pub struct Profiled<P> {
+    pub base: P,
+    pub profiles: BTreeMap<String, P>,
+}
+
+impl<'de, P: Deserialize<'de>> Deserialize<'de> for Profiled<P> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ProfiledVisitor(PhantomData))
+    }
+}
+
+struct ProfiledVisitor<P>(PhantomData<P>);
+
+impl<'de, P: Deserialize<'de>> Visitor<'de> for ProfiledVisitor<P> {
+    type Value = Profiled<P>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of settings")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let mut profiles = None;
+
+        let base = P::deserialize(MapAccessDeserializer::new(WithoutProfiles {
+            map,
+            profiles: &mut profiles,
+        }))?;
+
+        Ok(Profiled {
+            base,
+            profiles: profiles.unwrap_or_default(),
+        })
+    }
+}
+
+// Passes every entry through to the base settings, except for the
+// profiles key, which is deserialized separately as it's encountered.
+struct WithoutProfiles<'p, A, P> {
+    map: A,
+    profiles: &'p mut Option<BTreeMap<String, P>>,
+}
+
+impl<'de, 'p, A: MapAccess<'de>, P: Deserialize<'de>> MapAccess<'de> for WithoutProfiles<'p, A, P> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        while let Some(Key(key)) = self.map.next_key()? {
+            if key == PROFILES_KEY {
+                *self.profiles = Some(self.map.next_value()?);
+                continue;
+            }
+
+            return seed.deserialize(key.into_deserializer()).map(Some);
+        }
+
+        Ok(None)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.map.next_value_seed(seed)
+    }
+}
+
+// Keys are deserialized as a `str`, as RON identifiers
+// can't be deserialized as an owned `String`.
+struct Key(String);
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(KeyVisitor)
+    }
+}
+
+struct KeyVisitor;
+
+impl<'de> Visitor<'de> for KeyVisitor {
+    type Value = Key;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a setting name")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Key(value.to_owned()))
+    }
+}
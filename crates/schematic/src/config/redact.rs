@@ -0,0 +1,30 @@
+use std::cell::Cell;
+
+/// Placeholder that replaces the value of sensitive settings.
+pub const REDACTED: &str = "[REDACTED]";
+
+thread_local! {
+    static REDACTING: Cell<bool> = const { Cell::new(false) };
+}
+
+struct RedactGuard(bool);
+
+impl Drop for RedactGuard {
+    fn drop(&mut self) {
+        REDACTING.with(|redacting| redacting.set(self.0));
+    }
+}
+
+/// Run the provided operation in redaction mode, where the values of settings
+/// marked with `#[setting(sensitive)]` will be serialized as [`REDACTED`].
+/// This is useful when serializing configuration into logs or reports.
+pub fn redact_sensitive<R>(op: impl FnOnce() -> R) -> R {
+    let _guard = RedactGuard(REDACTING.with(|redacting| redacting.replace(true)));
+
+    op()
+}
+
+/// Return true if currently running within [`redact_sensitive`].
+pub fn is_redacting() -> bool {
+    REDACTING.with(|redacting| redacting.get())
+}
@@ -0,0 +1,207 @@
+use crate::config::errors::ConfigError;
+use crate::config::inspect::{find_closest_path, find_field, find_setting_paths, unwrap_nullable};
+use crate::config::path::{Path, PathSegment};
+use schematic_types::{Schema, SchemaType};
+use serde_json::{Map, Value};
+
+/// A single setting value, at a dot separated path, that overrides
+/// the loaded value, like `server.port=8080`.
+#[derive(Clone, Debug)]
+pub struct SettingOverride {
+    /// Path to the setting, resolved against the schema.
+    pub path: Path,
+
+    /// Value parsed into the setting's type.
+    pub value: Value,
+}
+
+impl SettingOverride {
+    /// Resolve the path against the provided schema, and parse the value into the
+    /// setting's type. Struct fields, map keys, and list indices are supported.
+    pub fn new(schema: &Schema, path: &str, value: &str) -> Result<Self, ConfigError> {
+        let Some((resolved, setting)) =
+            Path::parse(path).and_then(|parsed| resolve_path(schema, &parsed))
+        else {
+            return Err(unknown_setting(schema, path));
+        };
+
+        let value =
+            parse_value(setting, value).map_err(|message| ConfigError::InvalidOverrideValue {
+                path: resolved.to_string(),
+                message,
+            })?;
+
+        Ok(Self {
+            path: resolved,
+            value,
+        })
+    }
+
+    /// Parse a `path=value` pair, and create a new instance with [`SettingOverride::new`].
+    pub fn parse(schema: &Schema, pair: &str) -> Result<Self, ConfigError> {
+        let Some((path, value)) = pair.split_once('=') else {
+            return Err(ConfigError::InvalidOverrideValue {
+                path: pair.to_owned(),
+                message: "Expected a `path=value` pair.".into(),
+            });
+        };
+
+        Self::new(schema, path.trim(), value)
+    }
+
+    /// Apply the value to the provided value, at the path of the setting. Missing maps
+    /// are created, while lists may only be appended to at the index after the last item.
+    pub fn apply(&self, value: &mut Value) -> Result<(), ConfigError> {
+        let mut current = value;
+
+        for segment in self.path.segments() {
+            current = match segment {
+                PathSegment::Key(key) => {
+                    if !current.is_object() {
+                        *current = Value::Object(Map::new());
+                    }
+
+                    current
+                        .as_object_mut()
+                        .unwrap()
+                        .entry(key.to_owned())
+                        .or_insert(Value::Null)
+                }
+                PathSegment::Index(index) => {
+                    if !current.is_array() {
+                        *current = Value::Array(vec![]);
+                    }
+
+                    let items = current.as_array_mut().unwrap();
+                    let len = items.len();
+
+                    if *index == len {
+                        items.push(Value::Null);
+                    }
+
+                    items
+                        .get_mut(*index)
+                        .ok_or_else(|| ConfigError::InvalidOverrideValue {
+                            path: self.path.to_string(),
+                            message: format!(
+                                "Index {index} is out of bounds, list has {len} items."
+                            ),
+                        })?
+                }
+                _ => unreachable!(),
+            };
+        }
+
+        *current = self.value.clone();
+
+        Ok(())
+    }
+}
+
+fn resolve_path<'s>(schema: &'s Schema, path: &Path) -> Option<(Path, &'s Schema)> {
+    let mut resolved = Path::default();
+    let mut current = schema;
+
+    for segment in path.segments() {
+        let schema = unwrap_nullable(current);
+
+        match (&schema.ty, segment) {
+            (SchemaType::Struct(structure), PathSegment::Key(key)) => {
+                let (name, field) = find_field(structure, key)?;
+
+                if field.hidden {
+                    return None;
+                }
+
+                resolved = resolved.join_key(name);
+                current = field;
+            }
+            (SchemaType::Object(object), PathSegment::Key(key)) => {
+                resolved = resolved.join_key(key);
+                current = &object.value_type;
+            }
+            (SchemaType::Array(array), PathSegment::Index(index)) => {
+                resolved = resolved.join_index(*index);
+                current = &array.items_type;
+            }
+            _ => return None,
+        };
+    }
+
+    Some((resolved, current))
+}
+
+fn parse_value(schema: &Schema, value: &str) -> Result<Value, String> {
+    if schema.nullable && value == "null" {
+        return Ok(Value::Null);
+    }
+
+    let schema = unwrap_nullable(schema);
+
+    match &schema.ty {
+        SchemaType::Boolean(_) => value
+            .parse::<bool>()
+            .map(Value::Bool)
+            .map_err(|_| format!("Expected a boolean, received `{value}`.")),
+        SchemaType::Integer(_) => value
+            .parse::<i64>()
+            .map(Value::from)
+            .or_else(|_| value.parse::<u64>().map(Value::from))
+            .map_err(|_| format!("Expected an integer, received `{value}`.")),
+        SchemaType::Float(_) => value
+            .parse::<f64>()
+            .map(Value::from)
+            .map_err(|_| format!("Expected a number, received `{value}`.")),
+        SchemaType::Array(_) | SchemaType::Tuple(_) => match serde_json::from_str(value) {
+            Ok(Value::Array(items)) => Ok(Value::Array(items)),
+            _ => Err(format!("Expected a JSON array, received `{value}`.")),
+        },
+        SchemaType::Object(_) | SchemaType::Struct(_) => match serde_json::from_str(value) {
+            Ok(Value::Object(map)) => Ok(Value::Object(map)),
+            _ => Err(format!("Expected a JSON object, received `{value}`.")),
+        },
+        SchemaType::Union(_) | SchemaType::Unknown => {
+            Ok(serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_owned())))
+        }
+        _ => Ok(Value::String(value.to_owned())),
+    }
+}
+
+fn unknown_setting(schema: &Schema, path: &str) -> ConfigError {
+    let paths = find_setting_paths(schema);
+    let mut help = vec![];
+
+    if let Some(suggestion) = Path::parse(path)
+        .as_ref()
+        .and_then(|parsed| find_closest_path(parsed, &paths))
+    {
+        help.push(format!("Did you mean `{suggestion}`?"));
+    }
+
+    let mut expected = paths
+        .iter()
+        .map(|path| path.to_string())
+        .collect::<Vec<_>>();
+
+    expected.sort();
+
+    if !expected.is_empty() {
+        help.push(format!(
+            "Expected one of {}.",
+            expected
+                .iter()
+                .map(|path| format!("`{path}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    ConfigError::UnknownOverrideSetting {
+        path: path.to_owned(),
+        help: if help.is_empty() {
+            None
+        } else {
+            Some(help.join(" "))
+        },
+    }
+}
@@ -1,6 +1,10 @@
 use crate::config::cacher::BoxedCacher;
 use crate::config::errors::ConfigError;
 use crate::config::format::Format;
+use crate::config::format_parser::{is_loading_format, FormatRegistry};
+use crate::config::inspect::find_key_span;
+use crate::config::parser::ParserError;
+use crate::config::path::Path;
 use serde::Deserialize;
 use serde::{de::DeserializeOwned, Serialize};
 use std::fs;
@@ -12,7 +16,12 @@ use tracing::instrument;
 #[serde(tag = "type", rename_all = "kebab-case")]
 pub enum Source {
     /// Inline code snippet of the configuration.
-    Code { code: String, format: Format },
+    Code {
+        // May contain sensitive values, so redact the entire snippet
+        #[serde(serialize_with = "crate::internal::serialize_sensitive")]
+        code: String,
+        format: Format,
+    },
 
     /// File system path to the configuration.
     File {
@@ -33,9 +42,19 @@ impl Source {
     /// - Will be a file, if the file ends in an extension, or contains path separators.
     /// - Otherwise will be an error.
     pub fn new(value: &str, parent_source: Option<&Source>) -> Result<Source, ConfigError> {
+        Source::new_with_formats(value, parent_source, &FormatRegistry::default())
+    }
+
+    /// Create a new source like [`Source::new`], but will also detect the
+    /// registered custom formats from the file extension.
+    pub(crate) fn new_with_formats(
+        value: &str,
+        parent_source: Option<&Source>,
+        formats: &FormatRegistry,
+    ) -> Result<Source, ConfigError> {
         // Extending from a URL is allowed from any parent source
         if is_url_like(value) {
-            return Source::url(value);
+            return Source::url_with_format(value, detect_format(value, formats)?);
         }
 
         // Extending from a file is only allowed from file parent sources
@@ -47,7 +66,7 @@ impl Source {
             };
 
             if parent_source.is_none() {
-                return Source::file(value, true);
+                return Source::file_with_format(value, true, detect_format(value, formats)?);
             }
 
             if let Source::File {
@@ -61,7 +80,9 @@ impl Source {
                     path = parent_path.parent().unwrap().join(path);
                 }
 
-                return Source::file(path, true);
+                let format = detect_format(path.to_str().unwrap_or_default(), formats)?;
+
+                return Source::file_with_format(path, true, format);
             } else {
                 return Err(ConfigError::ExtendsFromParentFileOnly);
             }
@@ -77,69 +98,121 @@ impl Source {
         Ok(Source::Code { code, format })
     }
 
-    /// Create a new file source with the provided path.
+    /// Create a new file source with the provided path. If the path does not
+    /// have a file extension, the format will be detected from its content.
     pub fn file<T: TryInto<PathBuf>>(path: T, required: bool) -> Result<Source, ConfigError> {
         let path: PathBuf = path.try_into().map_err(|_| ConfigError::InvalidFile)?;
 
         Ok(Source::File {
-            format: Format::detect(path.to_str().unwrap_or_default())?,
+            format: detect_format(
+                path.to_str().unwrap_or_default(),
+                &FormatRegistry::default(),
+            )?,
+            path,
+            required,
+        })
+    }
+
+    /// Create a new file source with the provided path and explicit format.
+    pub fn file_with_format<T: TryInto<PathBuf>>(
+        path: T,
+        required: bool,
+        format: Format,
+    ) -> Result<Source, ConfigError> {
+        let path: PathBuf = path.try_into().map_err(|_| ConfigError::InvalidFile)?;
+
+        Ok(Source::File {
+            format,
             path,
             required,
         })
     }
 
-    /// Create a new URL source with the provided URL.
+    /// Create a new URL source with the provided URL. If the URL does not
+    /// have a file extension, the format will be detected from the `Content-Type`
+    /// header of the response, or its content.
     pub fn url<T: TryInto<String>>(url: T) -> Result<Source, ConfigError> {
         let url: String = url.try_into().map_err(|_| ConfigError::InvalidUrl)?;
 
         Ok(Source::Url {
-            format: Format::detect(&url)?,
+            format: detect_format(&url, &FormatRegistry::default())?,
             url,
         })
     }
 
-    /// Parse the source contents according to the required format.
-    #[instrument(name = "parse_config_source", skip(cacher, help), fields(source = ?self))]
-    pub fn parse<D>(
+    /// Create a new URL source with the provided URL and explicit format.
+    pub fn url_with_format<T: TryInto<String>>(
+        url: T,
+        format: Format,
+    ) -> Result<Source, ConfigError> {
+        let url: String = url.try_into().map_err(|_| ConfigError::InvalidUrl)?;
+
+        Ok(Source::Url { format, url })
+    }
+
+    /// Read the source contents as a string, without parsing it.
+    pub fn read(&self, cacher: &mut BoxedCacher) -> Result<String, ConfigError> {
+        self.read_with_format(cacher).map(|(content, _)| content)
+    }
+
+    /// Read the source contents as a string, without parsing it, and resolve
+    /// the format of the contents, if it has not been defined.
+    #[instrument(name = "read_config_source", skip(cacher), fields(source = ?self))]
+    pub fn read_with_format(
         &self,
-        location: &str,
         cacher: &mut BoxedCacher,
-        help: Option<&str>,
-    ) -> Result<D, ConfigError>
-    where
-        D: DeserializeOwned,
-    {
-        let handle_error = |error: crate::config::ParserError| ConfigError::Parser {
-            config: location.to_owned(),
-            error,
-            help: help.map(|h| h.to_owned()),
-        };
+    ) -> Result<(String, Format), ConfigError> {
+        let (content, content_type) = self.read_content(cacher)?;
+        let format = self.resolve_format(&content, content_type.as_deref())?;
+
+        Ok((content, format))
+    }
+
+    /// Resolve the format of the provided contents, that were previously read
+    /// from this source. If the format has not been defined, it will be detected
+    /// from the `Content-Type` header (for URLs), or the contents.
+    pub fn resolve_format(
+        &self,
+        content: &str,
+        content_type: Option<&str>,
+    ) -> Result<Format, ConfigError> {
+        let format = self.get_format();
 
+        if *format != Format::None {
+            return Ok(*format);
+        }
+
+        if let Some(format) = content_type.and_then(Format::from_content_type) {
+            return Ok(format);
+        }
+
+        Format::detect_from_content(self.as_str(), content)
+    }
+
+    #[cfg_attr(not(feature = "url"), allow(unused_variables))]
+    fn read_content(
+        &self,
+        cacher: &mut BoxedCacher,
+    ) -> Result<(String, Option<String>), ConfigError> {
         match self {
-            Source::Code { code, format } => format
-                .parse(code.to_owned(), location)
-                .map_err(handle_error),
-            Source::File {
-                path,
-                format,
-                required,
-            } => {
-                let content = if path.exists() {
-                    fs::read_to_string(path).map_err(|error| ConfigError::ReadFileFailed {
-                        path: path.to_path_buf(),
-                        error: Box::new(error),
-                    })?
+            Source::Code { code, .. } => Ok((code.to_owned(), None)),
+            Source::File { path, required, .. } => {
+                if path.exists() {
+                    fs::read_to_string(path)
+                        .map(|content| (content, None))
+                        .map_err(|error| ConfigError::ReadFileFailed {
+                            path: path.to_path_buf(),
+                            error: Box::new(error),
+                        })
                 } else {
                     if *required {
                         return Err(ConfigError::MissingFile(path.to_path_buf()));
                     }
 
-                    "".into()
-                };
-
-                format.parse(content, location).map_err(handle_error)
+                    Ok(("".into(), None))
+                }
             }
-            Source::Url { url, format } => {
+            Source::Url { url, .. } => {
                 if !is_secure_url(url) {
                     return Err(ConfigError::HttpsOnly(url.to_owned()));
                 }
@@ -151,20 +224,23 @@ impl Source {
                         error: Box::new(error),
                     };
 
-                    let content = if let Some(cache) = cacher.read(url)? {
-                        cache
-                    } else {
-                        let body = reqwest::blocking::get(url)
-                            .map_err(handle_reqwest_error)?
-                            .text()
-                            .map_err(handle_reqwest_error)?;
+                    // The content type is not cached, so cached
+                    // content will rely on content detection
+                    if let Some(cache) = cacher.read(url)? {
+                        return Ok((cache, None));
+                    }
 
-                        cacher.write(url, &body)?;
+                    let response = reqwest::blocking::get(url).map_err(handle_reqwest_error)?;
+                    let content_type = response
+                        .headers()
+                        .get(reqwest::header::CONTENT_TYPE)
+                        .and_then(|value| value.to_str().ok())
+                        .map(|value| value.to_owned());
+                    let body = response.text().map_err(handle_reqwest_error)?;
 
-                        body
-                    };
+                    cacher.write(url, &body)?;
 
-                    format.parse(content, location).map_err(handle_error)
+                    Ok((body, content_type))
                 }
 
                 #[cfg(not(feature = "url"))]
@@ -175,6 +251,123 @@ impl Source {
         }
     }
 
+    /// Read and parse the source contents according to the required format.
+    #[instrument(name = "parse_config_source", skip(cacher, help), fields(source = ?self))]
+    pub fn parse<D>(
+        &self,
+        location: &str,
+        cacher: &mut BoxedCacher,
+        help: Option<&str>,
+    ) -> Result<D, ConfigError>
+    where
+        D: DeserializeOwned,
+    {
+        let (content, format) = self.read_with_format(cacher)?;
+        let mut source = self.clone();
+
+        source.set_format(format);
+        source.parse_content(content, location, help)
+    }
+
+    /// Parse the provided contents, that were previously read from this source,
+    /// according to the required format. If the format has not been defined,
+    /// it will be detected from the contents.
+    pub fn parse_content<D>(
+        &self,
+        content: String,
+        location: &str,
+        help: Option<&str>,
+    ) -> Result<D, ConfigError>
+    where
+        D: DeserializeOwned,
+    {
+        self.parse_content_with_formats(content, location, help, &FormatRegistry::default())
+    }
+
+    /// Parse the provided contents like [`Source::parse_content`], but will
+    /// parse custom formats with the registered [`FormatParser`](crate::FormatParser).
+    pub(crate) fn parse_content_with_formats<D>(
+        &self,
+        content: String,
+        location: &str,
+        help: Option<&str>,
+        formats: &FormatRegistry,
+    ) -> Result<D, ConfigError>
+    where
+        D: DeserializeOwned,
+    {
+        self.resolve_format(&content, None)?
+            .parse_with_formats(content, location, formats)
+            .map_err(|error| ConfigError::Parser {
+                config: location.to_owned(),
+                error,
+                help: help.map(|h| h.to_owned()),
+            })
+    }
+
+    /// Deserialize the provided generic value, that was previously parsed from
+    /// the contents of this source. Since the value is detached from the source,
+    /// spans are determined by searching the contents for the failed setting.
+    pub fn parse_value<D>(
+        &self,
+        content: &str,
+        value: serde_json::Value,
+        location: &str,
+        help: Option<&str>,
+    ) -> Result<D, ConfigError>
+    where
+        D: DeserializeOwned,
+    {
+        let create_error = |path: Path, message: String| ConfigError::Parser {
+            config: location.to_owned(),
+            error: ParserError {
+                content: content.to_owned(),
+                path: path.to_string(),
+                first_span: None,
+                span: find_key_span(content, &path),
+                message,
+            },
+            help: help.map(|h| h.to_owned()),
+        };
+
+        // Key-value formats parse all values as strings, so they must
+        // be coerced again into the setting's type
+        #[cfg(any(feature = "ini", feature = "properties"))]
+        if crate::config::key_value::is_key_value_format(self.get_format()) {
+            return crate::config::key_value::deserialize_value(value)
+                .map_err(|(path, message)| create_error(path, message));
+        }
+
+        // HCL blocks are only a list when repeated, so single blocks must
+        // also be accepted as a list
+        #[cfg(feature = "hcl")]
+        if matches!(self.get_format(), Format::Hcl) {
+            return crate::config::hcl::deserialize_value(value)
+                .map_err(|(path, message)| create_error(path, message));
+        }
+
+        serde_path_to_error::deserialize(value)
+            .map_err(|error| create_error(Path::from(error.path()), error.inner().to_string()))
+    }
+
+    /// Return the format of the source.
+    pub fn get_format(&self) -> &Format {
+        match self {
+            Source::Code { format, .. } => format,
+            Source::File { format, .. } => format,
+            Source::Url { format, .. } => format,
+        }
+    }
+
+    /// Set the format of the source, overriding the detected format.
+    pub fn set_format(&mut self, format: Format) {
+        match self {
+            Source::Code { format: inner, .. } => *inner = format,
+            Source::File { format: inner, .. } => *inner = format,
+            Source::Url { format: inner, .. } => *inner = format,
+        };
+    }
+
     pub fn as_str(&self) -> &str {
         match self {
             Source::Code { .. } => "<code>",
@@ -184,9 +377,50 @@ impl Source {
     }
 }
 
-/// Returns true if the value ends in a supported file extension.
+/// Detect the format from the file extension of the provided value. If there
+/// is no file extension, the format will be detected when the source is read.
+pub(crate) fn detect_format(value: &str, formats: &FormatRegistry) -> Result<Format, ConfigError> {
+    match Format::detect_with_formats(value, formats) {
+        Err(ConfigError::UnsupportedFormat(..)) if !has_extension(value) => Ok(Format::None),
+        result => result,
+    }
+}
+
+/// Returns true if the file name of the value, either a file path or URL,
+/// has a file extension. Leading dots (`.apprc`) are not considered an extension.
+pub fn has_extension(value: &str) -> bool {
+    let path = value.split(['?', '#']).next().unwrap_or_default();
+    let path = match path.split_once("://") {
+        Some((_, rest)) => rest,
+        None => path,
+    };
+
+    // URLs without a path only contain a host name
+    if is_url_like(value) && !path.contains('/') {
+        return false;
+    }
+
+    path.rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches('.')
+        .contains('.')
+}
+
+/// Returns true if the value ends in a supported file extension, including the
+/// extensions of custom formats registered on the loader that is currently loading.
 pub fn is_source_format(value: &str) -> bool {
-    value.ends_with(".json")
+    is_loading_format(value) || is_builtin_source_format(value)
+}
+
+pub(crate) fn is_builtin_source_format(value: &str) -> bool {
+    value.ends_with(".hcl")
+        || value.ends_with(".ini")
+        || value.ends_with(".json")
+        || value.ends_with(".json5")
+        || value.ends_with(".jsonc")
+        || value.ends_with(".properties")
+        || value.ends_with(".ron")
         || value.ends_with(".toml")
         || value.ends_with(".yaml")
         || value.ends_with(".yml")
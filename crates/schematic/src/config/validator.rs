@@ -13,6 +13,9 @@ pub struct ValidateError {
 
     /// Relative path to the setting that failed validation.
     pub path: Path,
+
+    // Whether the failure is a non-fatal warning, instead of an error.
+    warning: bool,
 }
 
 impl ValidateError {
@@ -21,6 +24,7 @@ impl ValidateError {
         ValidateError {
             message: message.as_ref().to_owned(),
             path: Path::default(),
+            warning: false,
         }
     }
 
@@ -29,6 +33,7 @@ impl ValidateError {
         ValidateError {
             message: "this setting is required".into(),
             path: Path::default(),
+            warning: false,
         }
     }
 
@@ -37,6 +42,7 @@ impl ValidateError {
         ValidateError {
             message: message.as_ref().to_owned(),
             path,
+            warning: false,
         }
     }
 
@@ -53,8 +59,30 @@ impl ValidateError {
         ValidateError {
             message: message.as_ref().to_owned(),
             path: Path::new(segments.into_iter().collect()),
+            warning: false,
+        }
+    }
+
+    /// Create a new validation warning with the provided message. Warnings
+    /// will not fail loading, and will be reported as a [`ConfigWarning`](crate::ConfigWarning).
+    pub fn warning<T: AsRef<str>>(message: T) -> Self {
+        ValidateError {
+            message: message.as_ref().to_owned(),
+            path: Path::default(),
+            warning: true,
         }
     }
+
+    /// Mark the validation error as a non-fatal warning.
+    pub fn into_warning(mut self) -> Self {
+        self.warning = true;
+        self
+    }
+
+    /// Return true if the failure is a non-fatal warning, instead of an error.
+    pub fn is_warning(&self) -> bool {
+        self.warning
+    }
 }
 
 /// Either contains a single or multiple validation errors.
@@ -95,6 +123,24 @@ impl ValidateErrorType {
         }
     }
 
+    /// Split into a type containing only errors, and a type containing only warnings.
+    pub fn partition(self) -> (Option<ValidateErrorType>, Option<ValidateErrorType>) {
+        match self {
+            ValidateErrorType::Setting { ref error, .. } if error.is_warning() => {
+                (None, Some(self))
+            }
+            ValidateErrorType::Setting { .. } => (Some(self), None),
+            ValidateErrorType::Nested { error } => {
+                let (errors, warnings) = error.partition();
+
+                (
+                    errors.map(ValidateErrorType::nested),
+                    warnings.map(ValidateErrorType::nested),
+                )
+            }
+        }
+    }
+
     pub fn to_error_list(&self) -> Vec<String> {
         let mut list = vec![];
 
@@ -139,6 +185,33 @@ impl ValidatorError {
         self.errors.iter().map(|e| e.len()).sum()
     }
 
+    /// Split into an error containing only hard errors, and an error containing
+    /// only warnings. Either side will be `None` if it has no validation errors.
+    pub fn partition(self) -> (Option<ValidatorError>, Option<ValidatorError>) {
+        let mut errors = vec![];
+        let mut warnings = vec![];
+
+        for error_type in self.errors {
+            let (error, warning) = error_type.partition();
+
+            errors.extend(error);
+            warnings.extend(warning);
+        }
+
+        let wrap = |list: Vec<ValidateErrorType>| {
+            if list.is_empty() {
+                None
+            } else {
+                Some(ValidatorError {
+                    path: self.path.clone(),
+                    errors: list,
+                })
+            }
+        };
+
+        (wrap(errors), wrap(warnings))
+    }
+
     /// Return a string of all recursive validation errors, joined with newlines.
     pub fn to_full_string(&self) -> String {
         let mut message = String::new();
@@ -0,0 +1,155 @@
+use crate::config::validator::ValidatorError;
+use miette::{Diagnostic, SourceSpan};
+use starbase_styles::{Style, Stylize};
+use thiserror::Error;
+
+/// All configuration based warnings. Unlike [`ConfigError`](crate::ConfigError),
+/// warnings do not abort loading, and are collected into the load result.
+#[derive(Clone, Debug, Diagnostic, Error)]
+pub enum ConfigWarning {
+    #[diagnostic(code(config::setting::deprecated), severity(Warning))]
+    #[error(
+        "Setting {} in {} is deprecated.{}",
+        .path.style(Style::Id),
+        .config.style(Style::File),
+        .message.as_ref().map(|msg| format!(" {msg}")).unwrap_or_default(),
+    )]
+    DeprecatedSetting {
+        config: String,
+
+        #[source_code]
+        content: String,
+
+        message: Option<String>,
+
+        path: String,
+
+        #[label("Deprecated")]
+        span: Option<SourceSpan>,
+    },
+
+    #[diagnostic(code(config::env::deprecated), severity(Warning))]
+    #[error(
+        "Environment variable {} for setting {} is deprecated.",
+        .name.style(Style::Symbol),
+        .path.style(Style::Id),
+    )]
+    DeprecatedEnvVar {
+        name: String,
+
+        path: String,
+
+        #[help]
+        help: Option<String>,
+    },
+
+    #[diagnostic(code(config::env::unknown), severity(Warning))]
+    #[error(
+        "Unknown environment variable {} was ignored.",
+        .name.style(Style::Symbol),
+    )]
+    UnknownEnvVar {
+        name: String,
+
+        #[help]
+        help: Option<String>,
+    },
+
+    #[diagnostic(code(config::setting::redundant), severity(Warning))]
+    #[error(
+        "Setting {} in {} is equal to its default or inherited value, and can be removed.",
+        .path.style(Style::Id),
+        .config.style(Style::File),
+    )]
+    RedundantSetting {
+        config: String,
+
+        #[source_code]
+        content: String,
+
+        path: String,
+
+        #[label("Redundant")]
+        span: Option<SourceSpan>,
+    },
+
+    #[diagnostic(code(config::profile::unknown), severity(Warning))]
+    #[error(
+        "Profile {} was selected for {}, but was not found in any source.",
+        .name.style(Style::Id),
+        .config.style(Style::File),
+    )]
+    UnknownProfile { config: String, name: String },
+
+    #[diagnostic(code(config::setting::unknown), severity(Warning))]
+    #[error(
+        "Unknown setting {} in {} was ignored.",
+        .path.style(Style::Id),
+        .config.style(Style::File),
+    )]
+    UnknownSetting {
+        config: String,
+
+        #[source_code]
+        content: String,
+
+        path: String,
+
+        #[label("Unknown")]
+        span: Option<SourceSpan>,
+
+        #[help]
+        help: Option<String>,
+    },
+
+    #[diagnostic(code(config::url::stale_cache), severity(Warning))]
+    #[error(
+        "Failed to read URL {}, using a stale cached copy instead.",
+        .url.style(Style::Url),
+    )]
+    StaleCache {
+        url: String,
+
+        #[help]
+        error: String,
+    },
+
+    #[diagnostic(code(config::validate::warning), severity(Warning))]
+    #[error("Validation warnings for {}.", .config.style(Style::File))]
+    Validator {
+        config: String,
+
+        #[source]
+        error: Box<ValidatorError>,
+    },
+}
+
+impl ConfigWarning {
+    /// Return a full warning string, disregarding `miette` diagnostic structure.
+    /// This is extremely useful for debugging and tests, and less for application use.
+    pub fn to_full_string(&self) -> String {
+        let mut message = self.to_string();
+
+        match self {
+            ConfigWarning::StaleCache { error, .. }
+            | ConfigWarning::DeprecatedEnvVar {
+                help: Some(error), ..
+            }
+            | ConfigWarning::UnknownEnvVar {
+                help: Some(error), ..
+            }
+            | ConfigWarning::UnknownSetting {
+                help: Some(error), ..
+            } => {
+                message.push(' ');
+                message.push_str(error);
+            }
+            ConfigWarning::Validator { error, .. } => {
+                message.push_str(&error.to_full_string());
+            }
+            _ => {}
+        };
+
+        message.trim().to_string()
+    }
+}
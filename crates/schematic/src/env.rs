@@ -1,4 +1,8 @@
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+use crate::HandlerError;
 use crate::{internal, ParseEnvResult};
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+use serde::de::DeserializeOwned;
 use std::str::FromStr;
 
 /// Ignore the environment variable if it's empty and fallback to the previous or default value.
@@ -12,6 +16,90 @@ pub fn ignore_empty<T: FromStr>(var: String) -> ParseEnvResult<T> {
     internal::parse_value(var).map(|v| Some(v))
 }
 
+/// Parse a variable as JSON into the required type. This supports nested configs
+/// (their partials), and collections of them.
+#[cfg(feature = "json")]
+pub fn json<T: DeserializeOwned>(var: String) -> ParseEnvResult<T> {
+    serde_json::from_str(&var)
+        .map(|v| Some(v))
+        .map_err(|error| HandlerError::new(format!("Failed to parse JSON. {error}")))
+}
+
+/// Parse a variable as TOML into the required type. This supports nested configs
+/// (their partials), and collections of them.
+#[cfg(feature = "toml")]
+pub fn toml<T: DeserializeOwned>(var: String) -> ParseEnvResult<T> {
+    toml::from_str(&var)
+        .map(|v| Some(v))
+        .map_err(|error| HandlerError::new(format!("Failed to parse TOML. {error}")))
+}
+
+/// Parse a variable as YAML into the required type. This supports nested configs
+/// (their partials), and collections of them.
+#[cfg(feature = "yaml")]
+pub fn yaml<T: DeserializeOwned>(var: String) -> ParseEnvResult<T> {
+    serde_yaml::from_str(&var)
+        .map(|v| Some(v))
+        .map_err(|error| HandlerError::new(format!("Failed to parse YAML. {error}")))
+}
+
+// Used by `#[setting(env_format)]`, so that a missing Cargo feature is
+// reported as a compile error on the attribute, instead of a missing function.
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "json")]
+macro_rules! env_format_json {
+    () => {
+        $crate::env::json
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "json"))]
+macro_rules! env_format_json {
+    () => {
+        compile_error!("`env_format = \"json\"` requires the `json` Cargo feature of schematic.")
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "toml")]
+macro_rules! env_format_toml {
+    () => {
+        $crate::env::toml
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "toml"))]
+macro_rules! env_format_toml {
+    () => {
+        compile_error!("`env_format = \"toml\"` requires the `toml` Cargo feature of schematic.")
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "yaml")]
+macro_rules! env_format_yaml {
+    () => {
+        $crate::env::yaml
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "yaml"))]
+macro_rules! env_format_yaml {
+    () => {
+        compile_error!("`env_format = \"yaml\"` requires the `yaml` Cargo feature of schematic.")
+    };
+}
+
 /// Parse a string into a boolean. Will parse `1`, `true`, `yes`, `on`,
 /// and `enabled` as true, and everything else as false.
 pub fn parse_bool(var: String) -> ParseEnvResult<bool> {
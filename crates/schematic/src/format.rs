@@ -4,23 +4,83 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Format {
-    // This is to simply handle the use case when no features are
+    // This also handles the use case when no features are
     // enabled. If this doesn't exist, Rust errors with no variants.
-    #[doc(hidden)]
+    /// An unknown format, that will be detected from the content
+    /// when the source is read.
     #[default]
     None,
 
+    #[cfg(feature = "hcl")]
+    Hcl,
+
+    #[cfg(feature = "ini")]
+    Ini,
+
     #[cfg(feature = "json")]
     Json,
 
+    #[cfg(feature = "json5")]
+    Json5,
+
+    #[cfg(feature = "properties")]
+    Properties,
+
+    #[cfg(feature = "ron")]
+    Ron,
+
     #[cfg(feature = "toml")]
     Toml,
 
     #[cfg(feature = "yaml")]
     Yaml,
+
+    /// A custom format, with the name of a [`FormatParser`](crate::FormatParser) that was
+    /// registered on the loader. Since parsers are not available outside of the loader,
+    /// custom formats can be serialized, but not deserialized.
+    #[cfg(feature = "config")]
+    #[serde(skip_deserializing)]
+    Custom(CustomFormatName),
 }
 
+/// Name of a registered custom format. Aliased so that serde does not attempt
+/// to borrow the name from the deserializer.
+pub type CustomFormatName = &'static str;
+
 impl Format {
+    pub fn is_custom(&self) -> bool {
+        #[cfg(feature = "config")]
+        {
+            matches!(self, Format::Custom(_))
+        }
+        #[cfg(not(feature = "config"))]
+        {
+            false
+        }
+    }
+
+    pub fn is_hcl(&self) -> bool {
+        #[cfg(feature = "hcl")]
+        {
+            matches!(self, Format::Hcl)
+        }
+        #[cfg(not(feature = "hcl"))]
+        {
+            false
+        }
+    }
+
+    pub fn is_ini(&self) -> bool {
+        #[cfg(feature = "ini")]
+        {
+            matches!(self, Format::Ini)
+        }
+        #[cfg(not(feature = "ini"))]
+        {
+            false
+        }
+    }
+
     pub fn is_json(&self) -> bool {
         #[cfg(feature = "json")]
         {
@@ -32,6 +92,39 @@ impl Format {
         }
     }
 
+    pub fn is_json5(&self) -> bool {
+        #[cfg(feature = "json5")]
+        {
+            matches!(self, Format::Json5)
+        }
+        #[cfg(not(feature = "json5"))]
+        {
+            false
+        }
+    }
+
+    pub fn is_properties(&self) -> bool {
+        #[cfg(feature = "properties")]
+        {
+            matches!(self, Format::Properties)
+        }
+        #[cfg(not(feature = "properties"))]
+        {
+            false
+        }
+    }
+
+    pub fn is_ron(&self) -> bool {
+        #[cfg(feature = "ron")]
+        {
+            matches!(self, Format::Ron)
+        }
+        #[cfg(not(feature = "ron"))]
+        {
+            false
+        }
+    }
+
     pub fn is_toml(&self) -> bool {
         #[cfg(feature = "toml")]
         {
@@ -1,24 +1,101 @@
-use crate::config::{ConfigError, HandlerError, PartialConfig};
+use crate::config::{
+    is_redacting, record_env_var, Config, ConfigError, EnvProvider, HandlerError, PartialConfig,
+    Path, SettingEnvVars, ValidateError, REDACTED,
+};
 use crate::merge::merge_partial;
 use crate::ParseEnvResult;
 use schematic_types::Schema;
-use std::{env, str::FromStr};
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+use std::path::PathBuf;
+use std::{fmt, fs, str::FromStr};
+
+#[cfg(any(feature = "args", feature = "value_enum"))]
+pub use clap;
+pub use serde_json;
 
 pub fn handle_default_fn<T, E: std::error::Error>(result: Result<T, E>) -> Result<T, ConfigError> {
     result.map_err(|error| ConfigError::InvalidDefault(error.to_string()))
 }
 
-pub fn default_from_env_var<T: FromStr>(key: &str) -> ParseEnvResult<T> {
-    parse_from_env_var(key, |var| parse_value(var).map(|v| Some(v)))
+pub fn default_from_env_var<T: FromStr>(
+    env: &dyn EnvProvider,
+    keys: &[&str],
+    file_suffix: &str,
+) -> ParseEnvResult<T> {
+    parse_from_env_var(env, keys, file_suffix, parse_env_value)
+}
+
+pub fn parse_env_value<T: FromStr>(var: String) -> ParseEnvResult<T> {
+    parse_value(var).map(|v| Some(v))
+}
+
+struct EnvVar<'k> {
+    file: Option<PathBuf>,
+    key: &'k str,
+    value: String,
+}
+
+/// Read the first environment variable that is set, in order of precedence.
+/// If a variable is not set, the contents of the file that the suffixed
+/// variable (`KEY_FILE`) points to will be read instead. The key that was
+/// used is recorded, so that the loader can inspect it.
+fn read_env_var<'k>(
+    env: &dyn EnvProvider,
+    keys: &[&'k str],
+    file_suffix: &str,
+) -> Result<Option<EnvVar<'k>>, HandlerError> {
+    for key in keys {
+        if let Some(value) = env.get(key) {
+            record_env_var(key);
+
+            return Ok(Some(EnvVar {
+                file: None,
+                key,
+                value,
+            }));
+        }
+
+        if file_suffix.is_empty() {
+            continue;
+        }
+
+        if let Some(path) = env.get(&format!("{key}{file_suffix}")) {
+            let path = PathBuf::from(path);
+
+            let contents = fs::read_to_string(&path).map_err(|error| {
+                HandlerError(format!(
+                    "Failed to read file {} for environment variable {key}{file_suffix}. {error}",
+                    path.display()
+                ))
+            })?;
+
+            record_env_var(key);
+
+            return Ok(Some(EnvVar {
+                file: Some(path),
+                key,
+                value: contents.trim().to_owned(),
+            }));
+        }
+    }
+
+    Ok(None)
 }
 
 pub fn parse_from_env_var<T>(
-    key: &str,
+    env: &dyn EnvProvider,
+    keys: &[&str],
+    file_suffix: &str,
     parser: impl Fn(String) -> ParseEnvResult<T>,
 ) -> Result<Option<T>, HandlerError> {
-    if let Ok(var) = env::var(key) {
-        let value = parser(var).map_err(|error| {
-            HandlerError(format!("Invalid environment variable {key}. {error}"))
+    if let Some(EnvVar { file, key, value }) = read_env_var(env, keys, file_suffix)? {
+        let value = parser(value).map_err(|error| match file {
+            Some(path) => HandlerError(format!(
+                "Invalid environment variable {key}{file_suffix}, from file {}. {error}",
+                path.display()
+            )),
+            None => HandlerError(format!("Invalid environment variable {key}. {error}")),
         })?;
 
         return Ok(value);
@@ -27,6 +104,120 @@ pub fn parse_from_env_var<T>(
     Ok(None)
 }
 
+pub fn nested_env_vars<T: Config>(key: &str) -> Vec<SettingEnvVars> {
+    let parent = Path::default().join_key(key);
+
+    T::env_vars()
+        .into_iter()
+        .map(|mut var| {
+            var.path = parent.join_path(&var.path);
+            var
+        })
+        .collect()
+}
+
+/// Wrap an environment variable parser, so that the value being parsed is
+/// redacted from the error message when parsing fails.
+pub fn redact_env_var_error<T>(
+    parser: impl Fn(String) -> ParseEnvResult<T>,
+) -> impl Fn(String) -> ParseEnvResult<T> {
+    move |var| {
+        let value = Value::String(var.clone());
+
+        parser(var).map_err(|error| HandlerError(redact_message(&error.0, &value)))
+    }
+}
+
+pub fn redact_validate_error<T: Serialize>(mut error: ValidateError, value: &T) -> ValidateError {
+    if let Ok(value) = serde_json::to_value(value) {
+        error.message = redact_message(&error.message, &value);
+    }
+
+    error
+}
+
+/// Replace all whole word occurrences of the token, so that short values
+/// (like `1` or `true`) don't replace parts of unrelated numbers or words.
+fn redact_token(message: &str, token: &str) -> String {
+    let is_word_char = |ch: char| ch.is_alphanumeric() || ch == '_';
+    let mut result = String::with_capacity(message.len());
+    let mut last = 0;
+
+    for (index, _) in message.match_indices(token) {
+        let before = message[..index].chars().next_back();
+        let after = message[index + token.len()..].chars().next();
+
+        if before.is_some_and(is_word_char) || after.is_some_and(is_word_char) {
+            continue;
+        }
+
+        result.push_str(&message[last..index]);
+        result.push_str(REDACTED);
+        last = index + token.len();
+    }
+
+    result.push_str(&message[last..]);
+    result
+}
+
+fn redact_message(message: &str, value: &Value) -> String {
+    match value {
+        Value::String(inner) if !inner.is_empty() => message.replace(inner, REDACTED),
+        Value::Number(inner) => redact_token(message, &inner.to_string()),
+        Value::Bool(inner) => redact_token(message, &inner.to_string()),
+        Value::Array(items) => items
+            .iter()
+            .fold(message.to_owned(), |acc, item| redact_message(&acc, item)),
+        Value::Object(map) => map
+            .values()
+            .fold(message.to_owned(), |acc, item| redact_message(&acc, item)),
+        _ => message.to_owned(),
+    }
+}
+
+pub fn redact_setting_value(value: Option<&mut Value>) {
+    if let Some(value) = value.filter(|value| !value.is_null()) {
+        *value = Value::String(REDACTED.into());
+    }
+}
+
+pub fn redact_nested_value<T: Config>(value: Option<&mut Value>) {
+    if let Some(value) = value {
+        T::redact_settings(value);
+    }
+}
+
+pub fn redact_nested_list<T: Config>(value: Option<&mut Value>) {
+    if let Some(Value::Array(items)) = value {
+        items.iter_mut().for_each(T::redact_settings);
+    }
+}
+
+pub fn redact_nested_map<T: Config>(value: Option<&mut Value>) {
+    if let Some(Value::Object(map)) = value {
+        map.values_mut().for_each(T::redact_settings);
+    }
+}
+
+pub fn serialize_sensitive<T: Serialize, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if is_redacting() {
+        serializer.serialize_str(REDACTED)
+    } else {
+        value.serialize(serializer)
+    }
+}
+
+pub struct Redacted<'a, T>(pub &'a T);
+
+impl<'a, T> fmt::Debug for Redacted<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
 pub fn parse_value<T: FromStr, V: AsRef<str>>(value: V) -> Result<T, HandlerError> {
     let value = value.as_ref();
 
@@ -68,6 +259,19 @@ pub fn merge_partial_setting<T: PartialConfig>(
     }
 }
 
+pub fn insert_profiles_schema(schema: &mut Schema, mut profiles: Schema) {
+    use schematic_types::*;
+
+    if let SchemaType::Struct(inner) = &mut schema.ty {
+        profiles.description = Some("Named profiles that can be selected when loading, and will be merged on top of the base settings.".into());
+        profiles.optional = true;
+
+        inner
+            .fields
+            .insert(crate::config::PROFILES_KEY.into(), Box::new(profiles));
+    }
+}
+
 pub fn partialize_schema(schema: &mut Schema, force_partial: bool) {
     use schematic_types::*;
 
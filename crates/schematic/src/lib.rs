@@ -2,6 +2,10 @@
 
 mod format;
 
+/// Derive command line arguments from configurations.
+#[cfg(feature = "args")]
+pub mod args;
+
 #[cfg(feature = "config")]
 mod config;
 
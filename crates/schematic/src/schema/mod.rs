@@ -11,6 +11,10 @@ pub use schematic_types::*;
 #[cfg(feature = "renderer_json_schema")]
 pub use renderers::json_schema::{self, *};
 
+/// Renders HCL config templates.
+#[cfg(all(feature = "renderer_template", feature = "hcl"))]
+pub use renderers::hcl_template::*;
+
 /// Renders JSON config templates.
 #[cfg(all(feature = "renderer_template", feature = "json"))]
 pub use renderers::json_template::*;
@@ -24,6 +28,10 @@ pub use renderers::jsonc_template::*;
 #[allow(deprecated)]
 pub use renderers::template::{self, TemplateOptions};
 
+/// Renders RON config templates.
+#[cfg(all(feature = "renderer_template", feature = "ron"))]
+pub use renderers::ron_template::*;
+
 /// Renders TOML config templates.
 #[cfg(all(feature = "renderer_template", feature = "toml"))]
 pub use renderers::toml_template::*;
@@ -0,0 +1,262 @@
+use super::template::*;
+use crate::format::Format;
+use crate::schema::{RenderResult, SchemaRenderer};
+use indexmap::IndexMap;
+use schematic_types::*;
+use std::collections::HashSet;
+
+/// Renders HCL config templates.
+pub struct HclTemplateRenderer<'gen> {
+    ctx: TemplateContext,
+    schemas: Option<&'gen IndexMap<String, Schema>>,
+}
+
+impl<'gen> HclTemplateRenderer<'gen> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn default() -> Self {
+        HclTemplateRenderer::new(TemplateOptions::default())
+    }
+
+    pub fn new(options: TemplateOptions) -> Self {
+        HclTemplateRenderer {
+            ctx: TemplateContext::new(Format::Hcl, options),
+            schemas: None,
+        }
+    }
+
+    fn find_struct(&self, schema: &Schema) -> Option<StructType> {
+        match &schema.ty {
+            SchemaType::Reference(reference) => self
+                .schemas
+                .and_then(|schemas| schemas.get(reference))
+                .and_then(|schema| self.find_struct(schema)),
+            SchemaType::Struct(structure) => Some(structure.as_ref().clone()),
+            SchemaType::Union(uni) if uni.has_null() && uni.variants_types.len() == 2 => uni
+                .variants_types
+                .iter()
+                .find(|variant| !variant.is_null())
+                .and_then(|variant| self.find_struct(variant)),
+            _ => None,
+        }
+    }
+
+    fn render_block(&mut self, label: String, structure: &StructType) -> RenderResult<String> {
+        self.ctx.depth += 1;
+
+        let body = self.render_fields(structure, true)?;
+
+        self.ctx.depth -= 1;
+
+        if body.is_empty() {
+            return Ok(format!("{label} {{}}"));
+        }
+
+        Ok(format!(
+            "{label} {{\n{}\n{}}}",
+            body.join(self.ctx.gap()),
+            self.ctx.indent()
+        ))
+    }
+
+    /// Render the fields of a struct, either as a body where nested structs are
+    /// rendered as blocks, or within an object expression.
+    fn render_fields(&mut self, structure: &StructType, blocks: bool) -> RenderResult<Vec<String>> {
+        let mut out = vec![];
+
+        for (name, field) in &structure.fields {
+            self.ctx.push_stack(name);
+
+            if self.ctx.is_hidden(field) {
+                self.ctx.pop_stack();
+                continue;
+            }
+
+            let key = self.ctx.get_stack_key();
+            let nested = if blocks && !field.write_only {
+                match &field.ty {
+                    SchemaType::Object(object) if self.ctx.is_expanded(&key) => self
+                        .find_struct(&object.value_type)
+                        .map(|structure| (Some(object.key_type.as_ref()), structure)),
+                    _ => self.find_struct(field).map(|structure| (None, structure)),
+                }
+            } else {
+                None
+            };
+
+            let prop = match nested {
+                // Labeled block for expanded maps
+                Some((Some(key_type), structure)) => {
+                    let mut label = self.render_schema(key_type)?;
+
+                    if label == EMPTY_STRING {
+                        label = "\"example\"".into();
+                    }
+
+                    self.render_block(format!("{name} {label}"), &structure)?
+                }
+                Some((None, structure)) => self.render_block(name.to_owned(), &structure)?,
+                None => {
+                    let value = if field.write_only {
+                        render_sensitive()?
+                    } else {
+                        self.render_schema(field)?
+                    };
+
+                    format!("{name} = {value}")
+                }
+            };
+
+            out.push(self.ctx.create_field(field, prop));
+
+            self.ctx.pop_stack();
+        }
+
+        Ok(out)
+    }
+}
+
+impl<'gen> SchemaRenderer<'gen, String> for HclTemplateRenderer<'gen> {
+    fn is_reference(&self, _name: &str) -> bool {
+        false
+    }
+
+    fn render_array(&mut self, array: &ArrayType, _schema: &Schema) -> RenderResult<String> {
+        let key = self.ctx.get_stack_key();
+
+        if !self.ctx.is_expanded(&key) {
+            return render_array(array);
+        }
+
+        self.ctx.depth += 1;
+
+        let item_indent = self.ctx.indent();
+        let item = self.render_schema(&array.items_type)?;
+
+        self.ctx.depth -= 1;
+
+        Ok(format!("[\n{}{item},\n{}]", item_indent, self.ctx.indent()))
+    }
+
+    fn render_boolean(&mut self, boolean: &BooleanType, _schema: &Schema) -> RenderResult<String> {
+        render_boolean(boolean)
+    }
+
+    fn render_enum(&mut self, enu: &EnumType, _schema: &Schema) -> RenderResult<String> {
+        render_enum(enu)
+    }
+
+    fn render_float(&mut self, float: &FloatType, _schema: &Schema) -> RenderResult<String> {
+        render_float(float)
+    }
+
+    fn render_integer(&mut self, integer: &IntegerType, _schema: &Schema) -> RenderResult<String> {
+        render_integer(integer)
+    }
+
+    fn render_literal(&mut self, literal: &LiteralType, _schema: &Schema) -> RenderResult<String> {
+        render_literal(literal)
+    }
+
+    fn render_null(&mut self, _schema: &Schema) -> RenderResult<String> {
+        render_null()
+    }
+
+    fn render_object(&mut self, object: &ObjectType, _schema: &Schema) -> RenderResult<String> {
+        let key = self.ctx.get_stack_key();
+
+        if !self.ctx.is_expanded(&key) {
+            return render_object(object);
+        }
+
+        self.ctx.depth += 1;
+
+        let item_indent = self.ctx.indent();
+        let value = self.render_schema(&object.value_type)?;
+
+        self.ctx.depth -= 1;
+
+        let mut key = self.render_schema(&object.key_type)?;
+
+        if key == EMPTY_STRING {
+            key = "example".into();
+        }
+
+        Ok(format!(
+            "{{\n{}{key} = {value}\n{}}}",
+            item_indent,
+            self.ctx.indent()
+        ))
+    }
+
+    fn render_reference(&mut self, reference: &str, _schema: &Schema) -> RenderResult<String> {
+        if let Some(schemas) = &self.schemas {
+            if let Some(schema) = schemas.get(reference) {
+                return self.render_schema_without_reference(schema);
+            }
+        }
+
+        render_reference(reference)
+    }
+
+    fn render_string(&mut self, string: &StringType, _schema: &Schema) -> RenderResult<String> {
+        render_string(string)
+    }
+
+    fn render_struct(&mut self, structure: &StructType, _schema: &Schema) -> RenderResult<String> {
+        // Blocks can't be used within expressions, so render an object instead
+        self.ctx.depth += 1;
+
+        let fields = self.render_fields(structure, false)?;
+
+        self.ctx.depth -= 1;
+
+        if fields.is_empty() {
+            return Ok("{}".into());
+        }
+
+        Ok(format!(
+            "{{\n{}\n{}}}",
+            fields.join(self.ctx.gap()),
+            self.ctx.indent()
+        ))
+    }
+
+    fn render_tuple(&mut self, tuple: &TupleType, _schema: &Schema) -> RenderResult<String> {
+        render_tuple(tuple, |schema| self.render_schema(schema))
+    }
+
+    fn render_union(&mut self, uni: &UnionType, _schema: &Schema) -> RenderResult<String> {
+        render_union(uni, |schema| self.render_schema(schema))
+    }
+
+    fn render_unknown(&mut self, _schema: &Schema) -> RenderResult<String> {
+        render_unknown()
+    }
+
+    fn render(
+        &mut self,
+        schemas: &'gen IndexMap<String, Schema>,
+        _references: &'gen HashSet<String>,
+    ) -> RenderResult {
+        self.schemas = Some(schemas);
+
+        let root = validate_root(schemas)?;
+
+        let SchemaType::Struct(structure) = &root.ty else {
+            unreachable!();
+        };
+
+        let mut template = self.render_fields(structure, true)?.join(self.ctx.gap());
+
+        // Inject the header and footer
+        template = format!(
+            "{}{template}{}",
+            self.ctx.options.header, self.ctx.options.footer
+        );
+
+        // And always add a trailing newline
+        template.push('\n');
+
+        Ok(template)
+    }
+}
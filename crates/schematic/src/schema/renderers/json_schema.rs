@@ -429,7 +429,7 @@ impl<'gen> SchemaRenderer<'gen, JsonSchema> for JsonSchemaRenderer<'gen> {
         let mut properties = BTreeMap::new();
         let mut required = BTreeSet::from_iter(structure.required.clone().unwrap_or_default());
 
-        for (name, field) in &structure.fields {
+        for (name, field) in &structure.fields_with_renamed() {
             if field.hidden {
                 continue;
             }
@@ -122,10 +122,15 @@ impl<'gen> SchemaRenderer<'gen, String> for JsoncTemplateRenderer<'gen> {
             self.ctx.push_stack(name);
 
             if !self.ctx.is_hidden(field) {
+                let value = if field.write_only {
+                    render_sensitive()?
+                } else {
+                    self.render_schema(field)?
+                };
                 let prop = format!(
                     "\"{}\": {}{}",
                     name,
-                    self.render_schema(field)?,
+                    value,
                     if index == last_index { "" } else { "," }
                 );
 
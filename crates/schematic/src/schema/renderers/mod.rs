@@ -1,12 +1,18 @@
 #[cfg(feature = "renderer_json_schema")]
 pub mod json_schema;
 
+#[cfg(all(feature = "renderer_template", feature = "hcl"))]
+pub mod hcl_template;
+
 #[cfg(all(feature = "renderer_template", feature = "json"))]
 pub mod json_template;
 
 #[cfg(all(feature = "renderer_template", feature = "json"))]
 pub mod jsonc_template;
 
+#[cfg(all(feature = "renderer_template", feature = "ron"))]
+pub mod ron_template;
+
 #[cfg(feature = "renderer_template")]
 pub mod template;
 
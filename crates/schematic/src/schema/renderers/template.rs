@@ -141,8 +141,12 @@ impl TemplateContext {
             });
         }
 
-        if let Some(env_var) = &field.env_var {
-            push(format!("@envvar {env_var}"));
+        for env_var in &field.env_var {
+            push(if field.deprecated_env_var.contains(env_var) {
+                format!("@envvar {env_var} (deprecated)")
+            } else {
+                format!("@envvar {env_var}")
+            });
         }
 
         if lines.is_empty() {
@@ -170,7 +174,7 @@ impl TemplateContext {
     }
 
     pub fn get_comment_prefix(&self) -> &str {
-        if self.format.is_json() {
+        if self.format.is_json() || self.format.is_ron() {
             "// "
         } else {
             "# "
@@ -283,6 +287,10 @@ pub fn render_reference(reference: &str) -> RenderResult {
 
 pub const EMPTY_STRING: &str = "\"\"";
 
+pub fn render_sensitive() -> RenderResult {
+    Ok("\"<sensitive>\"".into())
+}
+
 pub fn render_string(string: &StringType) -> RenderResult {
     if let Some(default) = &string.default {
         return Ok(lit_to_string(default));
@@ -178,7 +178,12 @@ impl<'gen> SchemaRenderer<'gen, String> for TomlTemplateRenderer<'gen> {
             self.ctx.push_stack(name);
 
             if !self.ctx.is_hidden(field) {
-                let prop = format!("{} = {}", name, self.render_schema(field)?,);
+                let value = if field.write_only {
+                    render_sensitive()?
+                } else {
+                    self.render_schema(field)?
+                };
+                let prop = format!("{} = {}", name, value);
 
                 out.push(self.ctx.create_field(field, prop));
             }
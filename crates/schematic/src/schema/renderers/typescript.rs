@@ -374,7 +374,7 @@ impl<'gen> SchemaRenderer<'gen, String> for TypeScriptRenderer<'gen> {
         let mut out = vec![];
         let indent = self.indent();
 
-        for (name, field) in &structure.fields {
+        for (name, field) in &structure.fields_with_renamed() {
             if field.hidden {
                 continue;
             }
@@ -421,8 +421,12 @@ impl<'gen> SchemaRenderer<'gen, String> for TypeScriptRenderer<'gen> {
                 });
             }
 
-            if let Some(env_var) = &field.env_var {
-                tags.push(format!("@envvar {env_var}"));
+            for env_var in &field.env_var {
+                tags.push(if field.deprecated_env_var.contains(env_var) {
+                    format!("@envvar {env_var} (deprecated)")
+                } else {
+                    format!("@envvar {env_var}")
+                });
             }
 
             out.push(self.wrap_in_comment(field.description.as_ref(), tags, row));
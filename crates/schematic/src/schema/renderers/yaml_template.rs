@@ -136,7 +136,11 @@ impl<'gen> SchemaRenderer<'gen, String> for YamlTemplateRenderer<'gen> {
                 self.ctx.depth += 1;
             }
 
-            let value = self.render_schema(field)?;
+            let value = if field.write_only {
+                render_sensitive()?
+            } else {
+                self.render_schema(field)?
+            };
             let prop = format!(
                 "{}:{}{}",
                 name,
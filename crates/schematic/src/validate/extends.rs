@@ -1,6 +1,6 @@
 use crate::config::{
-    is_file_like, is_secure_url, is_source_format, is_url_like, ExtendsFrom, Path, PathSegment,
-    ValidateError, ValidateResult,
+    has_extension, is_file_like, is_secure_url, is_source_format, is_url_like, ExtendsFrom, Path,
+    PathSegment, ValidateError, ValidateResult,
 };
 
 /// Validate an `extend` value is either a file path or secure URL.
@@ -19,7 +19,8 @@ pub fn extends_string<D, C>(
         ));
     }
 
-    if !value.is_empty() && !is_source_format(value) {
+    // Values without an extension will detect the format from their content
+    if !value.is_empty() && has_extension(value) && !is_source_format(value) {
         return Err(ValidateError::new(
             "invalid format, try a supported extension",
         ));
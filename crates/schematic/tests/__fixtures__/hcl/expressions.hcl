@@ -0,0 +1,9 @@
+string = "${upper(var.name)}-suffix"
+number = 3
+vector = [
+  var.a,
+  [for item in var.items : item.name],
+  <<EOT
+heredoc
+EOT
+]
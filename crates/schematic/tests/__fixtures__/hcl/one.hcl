@@ -0,0 +1,3 @@
+# Comments are supported
+string = "foo"
+vector = ["a", "b", "c"]
@@ -0,0 +1,7 @@
+items {
+  name = "a"
+}
+
+items {
+  name = "b"
+}
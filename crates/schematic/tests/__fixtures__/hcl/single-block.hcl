@@ -0,0 +1,7 @@
+server {
+  port = 8080
+}
+
+items {
+  name = "a"
+}
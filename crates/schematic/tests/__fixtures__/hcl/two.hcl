@@ -0,0 +1,4 @@
+// Both comment styles
+boolean = true
+string  = "bar"
+number  = 123
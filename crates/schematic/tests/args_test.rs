@@ -0,0 +1,147 @@
+#![allow(dead_code)]
+
+use schematic::*;
+use std::collections::HashMap;
+
+#[derive(Clone, ConfigEnum, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ArgsLevel {
+    #[default]
+    Info,
+    Debug,
+}
+
+#[derive(Debug, Config)]
+pub struct ArgsServer {
+    /// Host to bind to.
+    #[setting(default = "localhost")]
+    host: String,
+    #[setting(default = 80)]
+    port: usize,
+}
+
+/// Application settings.
+#[derive(Debug, Config)]
+#[config(rename_all = "camelCase")]
+pub struct ArgsConfig {
+    /// Enable debug mode.
+    debug: bool,
+    log_level: ArgsLevel,
+    ratio: f32,
+    tags: Vec<String>,
+    name: Option<String>,
+    #[setting(nested)]
+    server: ArgsServer,
+    // Unsupported types are skipped
+    map: HashMap<String, String>,
+}
+
+#[test]
+fn creates_flags_for_settings() {
+    let command = args::create_command::<ArgsConfig>();
+    let flags = command
+        .get_arguments()
+        .filter_map(|arg| arg.get_long())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        flags,
+        vec![
+            "debug",
+            "log-level",
+            "name",
+            "ratio",
+            "server.host",
+            "server.port",
+            "tags",
+        ]
+    );
+
+    let help = args::create_command::<ArgsConfig>()
+        .render_help()
+        .to_string();
+
+    assert!(help.contains("Application settings."));
+    assert!(help.contains("Enable debug mode."));
+    assert!(help.contains("Host to bind to."));
+}
+
+#[test]
+fn applies_args_after_sources_and_env() {
+    let result = ConfigLoader::<ArgsConfig>::new()
+        .code(
+            "debug: true\nname: file\nserver:\n  host: example.com\n  port: 8080",
+            Format::Yaml,
+        )
+        .unwrap()
+        .args([
+            "app",
+            "--debug=false",
+            "--log-level",
+            "debug",
+            "--ratio",
+            "0.5",
+            "--tags",
+            "a",
+            "--tags",
+            "b",
+            "--server.port",
+            "3000",
+        ])
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(!result.config.debug);
+    assert_eq!(result.config.log_level, ArgsLevel::Debug);
+    assert_eq!(result.config.ratio, 0.5);
+    assert_eq!(result.config.tags, vec!["a", "b"]);
+    assert_eq!(result.config.name.as_deref(), Some("file"));
+    assert_eq!(result.config.server.host, "example.com");
+    assert_eq!(result.config.server.port, 3000);
+}
+
+#[test]
+fn supports_boolean_flags_without_value() {
+    let result = ConfigLoader::<ArgsConfig>::new()
+        .args(["app", "--debug"])
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(result.config.debug);
+}
+
+#[test]
+fn supports_custom_matches() {
+    let matches = args::create_command::<ArgsConfig>()
+        .arg(clap::Arg::new("verbose").long("verbose").num_args(0))
+        .get_matches_from(["app", "--verbose", "--name", "custom"]);
+
+    let result = ConfigLoader::<ArgsConfig>::new()
+        .arg_matches(&matches)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.name.as_deref(), Some("custom"));
+}
+
+#[test]
+fn errors_for_invalid_args() {
+    let error = ConfigLoader::<ArgsConfig>::new()
+        .args(["app", "--server.port", "abc"])
+        .err()
+        .unwrap();
+
+    assert!(matches!(error, ConfigError::InvalidArgs(_)));
+
+    let error = ConfigLoader::<ArgsConfig>::new()
+        .args(["app", "--log-level", "trace"])
+        .err()
+        .unwrap();
+
+    assert!(error
+        .to_full_string()
+        .contains("invalid value 'trace' for '--log-level <log-level>'"));
+}
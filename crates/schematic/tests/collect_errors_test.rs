@@ -0,0 +1,151 @@
+#![allow(dead_code)]
+
+use schematic::*;
+
+#[derive(Debug, Config)]
+pub struct NestedConfig {
+    setting: bool,
+    number: usize,
+}
+
+#[derive(Debug, Config)]
+pub struct BaseConfig {
+    setting: bool,
+    string: String,
+    #[setting(nested)]
+    nested: NestedConfig,
+}
+
+const CONTENT: &str = r#"{
+    "setting": 123,
+    "string": "valid",
+    "nested": {
+        "setting": "abc",
+        "number": true
+    }
+}"#;
+
+#[test]
+fn fails_on_first_error_by_default() {
+    let error = ConfigLoader::<BaseConfig>::new()
+        .code(CONTENT, Format::Json)
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    assert!(matches!(error, ConfigError::Parser { .. }));
+}
+
+#[test]
+fn collects_all_errors() {
+    let error = ConfigLoader::<BaseConfig>::new()
+        .collect_parse_errors()
+        .code(CONTENT, Format::Json)
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Failed to parse 3 settings in BaseConfig. \n  setting: invalid type: integer `123`, expected a boolean\n  nested.setting: invalid type: string \"abc\", expected a boolean\n  nested.number: invalid type: boolean `true`, expected usize"
+    );
+}
+
+#[test]
+fn includes_span_for_each_error() {
+    let error = ConfigLoader::<BaseConfig>::new()
+        .collect_parse_errors()
+        .code(
+            "setting: 123\nnested:\n  setting: abc\n  number: 1",
+            Format::Yaml,
+        )
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    let ConfigError::Parsers { errors, .. } = error else {
+        panic!("Expected multiple parser errors.");
+    };
+
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].path, "setting");
+    assert_eq!(errors[0].span, Some((0, 7).into()));
+    assert_eq!(errors[1].path, "nested.setting");
+    assert_eq!(errors[1].span, Some((23, 7).into()));
+}
+
+#[cfg(feature = "ini")]
+#[test]
+fn collects_only_invalid_ini_settings() {
+    let error = ConfigLoader::<BaseConfig>::new()
+        .collect_parse_errors()
+        .code(
+            "setting = true\nstring = valid\n\n[nested]\nsetting = abc\nnumber = -1",
+            Format::Ini,
+        )
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    let ConfigError::Parsers { errors, .. } = error else {
+        panic!("Expected multiple parser errors.");
+    };
+
+    assert_eq!(
+        errors.iter().map(|error| &error.path).collect::<Vec<_>>(),
+        ["nested.setting", "nested.number"]
+    );
+}
+
+#[cfg(feature = "ini")]
+#[test]
+fn loads_valid_ini_sources() {
+    let result = ConfigLoader::<BaseConfig>::new()
+        .collect_parse_errors()
+        .code(
+            "setting = true\nstring = valid\n\n[nested]\nsetting = false\nnumber = 4",
+            Format::Ini,
+        )
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(result.config.setting);
+    assert_eq!(result.config.nested.number, 4);
+}
+
+#[test]
+fn returns_single_error_as_is() {
+    let error = ConfigLoader::<BaseConfig>::new()
+        .collect_parse_errors()
+        .code(r#"{ "setting": 123, "string": "valid" }"#, Format::Json)
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Failed to parse BaseConfig. setting: invalid type: integer `123`, expected a boolean at line 1 column 16"
+    );
+}
+
+#[test]
+fn loads_valid_sources() {
+    let result = ConfigLoader::<BaseConfig>::new()
+        .collect_parse_errors()
+        .code(
+            r#"{ "setting": true, "nested": { "number": 1 } }"#,
+            Format::Json,
+        )
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(result.config.setting);
+    assert_eq!(result.config.nested.number, 1);
+}
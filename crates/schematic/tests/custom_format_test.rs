@@ -0,0 +1,187 @@
+#![allow(dead_code)]
+
+mod utils;
+
+use crate::utils::get_fixture_path;
+use schematic::*;
+use serde_json::{Map, Value};
+
+/// A line based format of `key: value` pairs, where values are parsed as JSON
+/// when possible, otherwise as strings.
+struct KeyValueFormat;
+
+impl FormatParser for KeyValueFormat {
+    fn name(&self) -> &'static str {
+        "kv"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["kv"]
+    }
+
+    fn parse(&self, content: &str) -> Result<Value, ParserError> {
+        let mut map = Map::new();
+
+        for line in content.lines().filter(|line| !line.trim().is_empty()) {
+            let Some((key, value)) = line.split_once(':') else {
+                return Err(ParserError {
+                    content: content.to_owned(),
+                    path: String::new(),
+                    first_span: None,
+                    span: None,
+                    message: format!("Missing separator in `{line}`."),
+                });
+            };
+
+            let value = value.trim();
+
+            map.insert(
+                key.trim().to_owned(),
+                serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.into())),
+            );
+        }
+
+        Ok(Value::Object(map))
+    }
+}
+
+#[derive(Debug, Config)]
+pub struct Config {
+    #[setting(extend)]
+    extends: Option<String>,
+    boolean: bool,
+    string: String,
+    number: usize,
+}
+
+#[test]
+fn detects_registered_formats() {
+    let mut formats = FormatRegistry::default();
+    formats.register(KeyValueFormat);
+
+    assert_eq!(
+        Format::detect_with_formats("config.kv", &formats).unwrap(),
+        Format::Custom("kv")
+    );
+    assert!(formats.is_source_format("https://domain.com/config.kv"));
+    assert!(formats.is_source_format("config.yml"));
+    assert!(!formats.is_source_format("config.akv"));
+    assert!(!is_source_format("config.kv"));
+}
+
+fn panic_on_validate<T, C>(_: &T, _: &C, _: &(), _: bool) -> ValidateResult {
+    panic!("validator panicked");
+}
+
+#[derive(Debug, Config)]
+pub struct PanicConfig {
+    #[setting(validate = panic_on_validate)]
+    string: String,
+}
+
+#[test]
+fn resets_registered_formats_when_loading_panics() {
+    let result = std::panic::catch_unwind(|| {
+        ConfigLoader::<PanicConfig>::new()
+            .register_format(KeyValueFormat)
+            .code("string: foo", Format::Custom("kv"))
+            .unwrap()
+            .load()
+    });
+
+    assert!(result.is_err());
+    assert!(!is_source_format("config.kv"));
+}
+
+#[test]
+fn errors_for_unregistered_extensions() {
+    let error = Format::detect("config.unknown").unwrap_err();
+
+    assert!(matches!(error, ConfigError::UnsupportedFormat(..)));
+}
+
+#[test]
+fn errors_for_formats_registered_on_another_loader() {
+    let root = get_fixture_path("custom");
+
+    ConfigLoader::<Config>::new()
+        .register_format(KeyValueFormat)
+        .file(root.join("one.kv"))
+        .unwrap();
+
+    let error = ConfigLoader::<Config>::new()
+        .file(root.join("one.kv"))
+        .err()
+        .unwrap();
+
+    assert!(matches!(error, ConfigError::UnsupportedFormat(..)));
+}
+
+#[test]
+fn loads_and_extends_files() {
+    let root = get_fixture_path("custom");
+
+    let result = ConfigLoader::<Config>::new()
+        .register_format(KeyValueFormat)
+        .file(root.join("base.kv"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(result.config.boolean);
+    assert_eq!(result.config.string, "bar");
+    assert_eq!(result.config.number, 123);
+    assert_eq!(
+        result.layers[0].source,
+        Source::File {
+            path: root.join("./one.kv"),
+            format: Format::Custom("kv"),
+            required: true,
+        }
+    );
+}
+
+#[test]
+fn includes_key_span_in_errors() {
+    let error = ConfigLoader::<Config>::new()
+        .register_format(KeyValueFormat)
+        .code("string: abc\nnumber: true", Format::Custom("kv"))
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Failed to parse Config. number: invalid type: boolean `true`, expected usize"
+    );
+
+    let ConfigError::Parser { error, .. } = error else {
+        panic!("Expected a parser error.");
+    };
+
+    assert_eq!(error.span, Some((12, 6).into()));
+}
+
+#[test]
+fn errors_for_unregistered_formats() {
+    let error = ConfigLoader::<Config>::new()
+        .code("string: abc", Format::Custom("unknown"))
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Failed to parse Config. : Format `unknown` has not been registered."
+    );
+}
+
+#[test]
+fn serializes_custom_formats() {
+    let value = serde_json::to_string(&Format::Custom("kv")).unwrap();
+
+    assert_eq!(value, r#"{"custom":"kv"}"#);
+    assert!(serde_json::from_str::<Format>(&value).is_err());
+}
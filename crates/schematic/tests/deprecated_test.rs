@@ -0,0 +1,97 @@
+#![allow(deprecated)]
+
+use schematic::*;
+
+#[derive(Debug, Config)]
+pub struct NestedDeprecated {
+    #[deprecated = "Use something else."]
+    pub legacy: bool,
+    pub value: String,
+}
+
+#[derive(Debug, Config)]
+pub struct RenamedConfig {
+    #[setting(renamed_from = "oldName")]
+    pub new_name: String,
+    #[deprecated]
+    pub unused: Option<usize>,
+    #[setting(nested)]
+    pub nested: NestedDeprecated,
+}
+
+#[test]
+fn maps_renamed_setting_to_new_field() {
+    let result = ConfigLoader::<RenamedConfig>::new()
+        .code(r#"{ "oldName": "abc" }"#, Format::Json)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.new_name, "abc");
+    assert_eq!(result.warnings.len(), 1);
+
+    let ConfigWarning::DeprecatedSetting {
+        path,
+        message,
+        span,
+        ..
+    } = &result.warnings[0]
+    else {
+        panic!();
+    };
+
+    assert_eq!(path, "oldName");
+    assert_eq!(message.as_deref(), Some("Renamed to `newName`."));
+    assert_eq!(*span, Some((3, 7).into()));
+}
+
+#[test]
+fn prefers_new_name_over_renamed() {
+    let result = ConfigLoader::<RenamedConfig>::new()
+        .code(r#"newName: "abc""#, Format::Yaml)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.new_name, "abc");
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn warns_for_deprecated_settings() {
+    let result = ConfigLoader::<RenamedConfig>::new()
+        .code(
+            "unused = 1\n\n[nested]\nlegacy = true\nvalue = \"abc\"",
+            Format::Toml,
+        )
+        .unwrap()
+        .load()
+        .unwrap();
+
+    let mut warnings = result
+        .warnings
+        .iter()
+        .map(|warning| warning.to_string())
+        .collect::<Vec<_>>();
+
+    warnings.sort();
+
+    assert_eq!(
+        warnings,
+        [
+            "Setting nested.legacy in RenamedConfig is deprecated. Use something else.",
+            "Setting unused in RenamedConfig is deprecated.",
+        ]
+    );
+}
+
+#[test]
+fn doesnt_warn_when_not_used() {
+    let result = ConfigLoader::<RenamedConfig>::new()
+        .code("{}", Format::Json)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(result.warnings.is_empty());
+}
@@ -0,0 +1,175 @@
+#![allow(dead_code)]
+
+use schematic::*;
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Debug, Config)]
+pub struct NestedConfig {
+    setting: bool,
+}
+
+#[derive(Debug, Config)]
+pub struct BaseConfig {
+    setting: bool,
+    #[setting(nested)]
+    nested: NestedConfig,
+}
+
+#[derive(Debug, Config)]
+pub struct MapConfig {
+    map: HashMap<String, usize>,
+}
+
+fn parse_strict(format: Format, content: &str) -> ParserError {
+    format
+        .parse_strict::<Value>(content.to_owned(), "test")
+        .err()
+        .unwrap()
+}
+
+#[test]
+fn keeps_last_value_by_default() {
+    let result = ConfigLoader::<MapConfig>::new()
+        .code(r#"{ "map": { "key": 1, "key": 2 } }"#, Format::Json)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.map.get("key"), Some(&2));
+}
+
+#[test]
+fn errors_for_duplicates_when_denied() {
+    let error = ConfigLoader::<BaseConfig>::new()
+        .code(
+            r#"{ "nested": { "setting": false, "setting": true } }"#,
+            Format::Json,
+        )
+        .unwrap()
+        .deny_duplicate_keys()
+        .load()
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Failed to parse BaseConfig. nested.setting: Duplicate key `nested.setting`."
+    );
+}
+
+#[test]
+fn allows_same_key_in_different_maps() {
+    Format::Json
+        .parse_strict::<Value>(
+            r#"{ "setting": true, "nested": { "setting": true }, "list": [{ "a": 1 }, { "a": 2 }] }"#
+                .into(),
+            "test",
+        )
+        .unwrap();
+}
+
+#[cfg(feature = "json")]
+mod json {
+    use super::*;
+
+    #[test]
+    fn includes_both_spans() {
+        let error = parse_strict(
+            Format::Json,
+            r#"{ "setting": true, "nested": { "setting": false, "setting": true } }"#,
+        );
+
+        assert_eq!(error.path, "nested.setting");
+        assert_eq!(error.first_span, Some((32, 7).into()));
+        assert_eq!(error.span, Some((50, 7).into()));
+    }
+
+    #[test]
+    fn detects_within_lists() {
+        let error = parse_strict(Format::Json, r#"{ "list": [{ "a": 1, "a": 2 }] }"#);
+
+        assert_eq!(error.path, "list[0].a");
+    }
+}
+
+#[cfg(feature = "json5")]
+mod json5 {
+    use super::*;
+
+    #[test]
+    fn includes_both_spans() {
+        let error = parse_strict(
+            Format::Json5,
+            "{\n  nested: {\n    setting: false,\n    setting: true,\n  },\n}",
+        );
+
+        assert_eq!(error.path, "nested.setting");
+        assert_eq!(error.first_span, Some((18, 7).into()));
+        assert_eq!(error.span, Some((38, 7).into()));
+    }
+}
+
+#[cfg(feature = "ron")]
+mod ron {
+    use super::*;
+
+    #[test]
+    fn includes_both_spans() {
+        let error = parse_strict(
+            Format::Ron,
+            "(\n  nested: (\n    setting: false,\n    setting: true,\n  ),\n)",
+        );
+
+        assert_eq!(error.path, "nested.setting");
+        assert_eq!(error.first_span, Some((18, 7).into()));
+        assert_eq!(error.span, Some((38, 7).into()));
+    }
+}
+
+#[cfg(feature = "yaml")]
+mod yaml {
+    use super::*;
+
+    #[test]
+    fn includes_both_spans() {
+        let error = parse_strict(
+            Format::Yaml,
+            "setting: true\nnested:\n  setting: false\n  setting: true",
+        );
+
+        assert_eq!(error.path, "nested.setting");
+        assert_eq!(error.first_span, Some((24, 7).into()));
+        assert_eq!(error.span, Some((41, 7).into()));
+    }
+
+    #[test]
+    fn errors_for_duplicates_when_denied() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("setting: true\nsetting: false", Format::Yaml)
+            .unwrap()
+            .deny_duplicate_keys()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. setting: Duplicate key `setting`."
+        );
+    }
+}
+
+#[cfg(feature = "ini")]
+mod ini {
+    use super::*;
+
+    #[test]
+    fn includes_both_spans() {
+        let error = parse_strict(Format::Ini, "[nested]\nsetting = false\nsetting = true");
+
+        assert_eq!(error.path, "nested.setting");
+        assert_eq!(error.first_span, Some((9, 0).into()));
+        assert_eq!(error.span, Some((25, 0).into()));
+    }
+}
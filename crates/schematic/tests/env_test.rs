@@ -2,6 +2,7 @@
 
 use schematic::*;
 use serial_test::serial;
+use std::collections::HashMap;
 use std::{env, path::PathBuf};
 
 #[derive(Debug, Config)]
@@ -206,6 +207,150 @@ fn loads_from_prefixed() {
     assert_eq!(result.config.list2, vec![1, 2, 3]);
 }
 
+#[derive(Debug, Config)]
+#[config(env_file_suffix = "_PATH")]
+pub struct EnvVarsFileSuffix {
+    #[setting(env = "ENV_SECRET")]
+    secret: String,
+}
+
+#[test]
+#[serial]
+fn reads_from_file_env_var() {
+    reset_vars();
+
+    let sandbox = starbase_sandbox::create_empty_sandbox();
+    sandbox.create_file("string", "  foo\n");
+    sandbox.create_file("list", "1;2;3\n");
+
+    env::set_var("ENV_STRING_FILE", sandbox.path().join("string"));
+    env::set_var("ENV_VEC_NUMBER_FILE", sandbox.path().join("list"));
+
+    let result = ConfigLoader::<EnvVars>::new().load().unwrap();
+    let parse_result = ConfigLoader::<EnvVarParse>::new().load().unwrap();
+
+    env::remove_var("ENV_STRING_FILE");
+    env::remove_var("ENV_VEC_NUMBER_FILE");
+
+    assert_eq!(result.config.string, "foo");
+    assert_eq!(parse_result.config.list2, vec![1, 2, 3]);
+}
+
+#[test]
+#[serial]
+fn env_var_takes_precedence_over_file() {
+    reset_vars();
+
+    let sandbox = starbase_sandbox::create_empty_sandbox();
+    sandbox.create_file("string", "bar");
+
+    env::set_var("ENV_STRING", "foo");
+    env::set_var("ENV_STRING_FILE", sandbox.path().join("string"));
+
+    let result = ConfigLoader::<EnvVars>::new().load().unwrap();
+
+    env::remove_var("ENV_STRING_FILE");
+
+    assert_eq!(result.config.string, "foo");
+}
+
+#[test]
+#[serial]
+fn supports_custom_file_suffix() {
+    reset_vars();
+
+    let sandbox = starbase_sandbox::create_empty_sandbox();
+    sandbox.create_file("secret", "abc");
+
+    env::set_var("ENV_SECRET_PATH", sandbox.path().join("secret"));
+
+    let result = ConfigLoader::<EnvVarsFileSuffix>::new().load().unwrap();
+
+    env::remove_var("ENV_SECRET_PATH");
+
+    assert_eq!(result.config.secret, "abc");
+}
+
+#[test]
+#[serial]
+fn errors_on_file_parse_fail() {
+    reset_vars();
+
+    let sandbox = starbase_sandbox::create_empty_sandbox();
+    sandbox.create_file("number", "abc");
+
+    env::set_var("ENV_NUMBER_FILE", sandbox.path().join("number"));
+
+    let error = ConfigLoader::<EnvVars>::new().load().err().unwrap();
+
+    env::remove_var("ENV_NUMBER_FILE");
+
+    assert!(error.to_full_string().starts_with(&format!(
+        "Invalid environment variable ENV_NUMBER_FILE, from file {}.",
+        sandbox.path().join("number").display()
+    )));
+}
+
+#[test]
+#[serial]
+fn errors_on_missing_file() {
+    reset_vars();
+    env::set_var("ENV_NUMBER_FILE", "missing/file");
+
+    let error = ConfigLoader::<EnvVars>::new().load().err().unwrap();
+
+    env::remove_var("ENV_NUMBER_FILE");
+
+    assert!(error
+        .to_full_string()
+        .starts_with("Failed to read file missing/file for environment variable ENV_NUMBER_FILE."));
+}
+
+#[derive(Debug, Config)]
+pub struct EnvVarsMultiple {
+    #[setting(env = ["ENV_API_TOKEN", "ENV_GITHUB_TOKEN"], deprecated_env = "ENV_TOKEN")]
+    token: String,
+}
+
+#[test]
+#[serial]
+fn uses_first_env_var_in_precedence() {
+    env::set_var("ENV_GITHUB_TOKEN", "github");
+    env::set_var("ENV_TOKEN", "old");
+
+    let result = ConfigLoader::<EnvVarsMultiple>::new().load().unwrap();
+
+    assert_eq!(result.config.token, "github");
+    assert!(result.warnings.is_empty());
+
+    env::set_var("ENV_API_TOKEN", "api");
+
+    let result = ConfigLoader::<EnvVarsMultiple>::new().load().unwrap();
+
+    env::remove_var("ENV_API_TOKEN");
+    env::remove_var("ENV_GITHUB_TOKEN");
+    env::remove_var("ENV_TOKEN");
+
+    assert_eq!(result.config.token, "api");
+}
+
+#[test]
+#[serial]
+fn warns_for_deprecated_env_var() {
+    env::set_var("ENV_TOKEN", "old");
+
+    let result = ConfigLoader::<EnvVarsMultiple>::new().load().unwrap();
+
+    env::remove_var("ENV_TOKEN");
+
+    assert_eq!(result.config.token, "old");
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(
+        result.warnings[0].to_full_string(),
+        "Environment variable ENV_TOKEN for setting token is deprecated. Use ENV_API_TOKEN instead."
+    );
+}
+
 #[cfg(feature = "renderer_json_schema")]
 #[test]
 fn generates_json_schema() {
@@ -241,3 +386,224 @@ fn generates_typescript() {
     assert!(file.exists());
     assert_snapshot!(std::fs::read_to_string(file).unwrap());
 }
+
+#[cfg(feature = "renderer_template")]
+#[test]
+fn generates_template_with_multiple_names() {
+    use starbase_sandbox::{assert_snapshot, create_empty_sandbox};
+
+    let sandbox = create_empty_sandbox();
+    let file = sandbox.path().join("config.yml");
+
+    let mut generator = schema::SchemaGenerator::default();
+    generator.add::<EnvVarsMultiple>();
+    generator
+        .generate(&file, schema::YamlTemplateRenderer::default())
+        .unwrap();
+
+    assert!(file.exists());
+    assert_snapshot!(std::fs::read_to_string(file).unwrap());
+}
+
+#[derive(Debug, Config)]
+pub struct EnvVarsTarget {
+    host: String,
+    #[setting(default = 80)]
+    port: usize,
+}
+
+#[derive(Debug, Config)]
+pub struct EnvVarsStructured {
+    #[setting(nested, env = "ENV_TARGET", env_format = "json")]
+    target: EnvVarsTarget,
+    #[setting(nested, env = "ENV_TARGET_LIST", env_format = "yaml")]
+    list: Vec<EnvVarsTarget>,
+    #[setting(nested, env = "ENV_TARGET_MAP", env_format = "json")]
+    map: HashMap<String, EnvVarsTarget>,
+    #[setting(env = "ENV_TAGS", parse_env = schematic::env::json)]
+    tags: Vec<String>,
+}
+
+#[test]
+#[serial]
+fn parses_structured_env_vars() {
+    env::set_var("ENV_TARGET", r#"{ "port": 8080 }"#);
+    env::set_var("ENV_TARGET_LIST", "- host: a\n- host: b\n  port: 443");
+    env::set_var("ENV_TARGET_MAP", r#"{ "x": { "host": "x.com" } }"#);
+    env::set_var("ENV_TAGS", r#"["a", "b"]"#);
+
+    let result = ConfigLoader::<EnvVarsStructured>::new()
+        .code("target:\n  host: localhost\n  port: 3000", Format::Yaml)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    env::remove_var("ENV_TARGET");
+    env::remove_var("ENV_TARGET_LIST");
+    env::remove_var("ENV_TARGET_MAP");
+    env::remove_var("ENV_TAGS");
+
+    // Merged on top of the loaded nested config
+    assert_eq!(result.config.target.host, "localhost");
+    assert_eq!(result.config.target.port, 8080);
+
+    assert_eq!(result.config.list.len(), 2);
+    assert_eq!(result.config.list[0].host, "a");
+    assert_eq!(result.config.list[0].port, 80);
+    assert_eq!(result.config.list[1].port, 443);
+
+    assert_eq!(result.config.map.get("x").unwrap().host, "x.com");
+    assert_eq!(result.config.tags, vec!["a", "b"]);
+}
+
+#[test]
+#[serial]
+fn errors_on_structured_parse_fail() {
+    env::set_var("ENV_TARGET", r#"{ "unknown": true }"#);
+
+    let error = ConfigLoader::<EnvVarsStructured>::new()
+        .load()
+        .err()
+        .unwrap();
+
+    env::remove_var("ENV_TARGET");
+
+    assert!(error.to_full_string().starts_with(
+        "Invalid environment variable ENV_TARGET. Failed to parse JSON. unknown field `unknown`"
+    ));
+}
+
+#[test]
+fn reads_from_custom_provider() {
+    let vars = HashMap::from_iter([
+        ("ENV_STRING".to_owned(), "foo".to_owned()),
+        ("ENV_NUMBER".to_owned(), "123".to_owned()),
+        ("ENV_LIST1".to_owned(), "1,2,3".to_owned()),
+    ]);
+
+    let result = ConfigLoader::<EnvVarsPrefixed>::new()
+        .set_env_provider(vars)
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.string, "foo");
+    assert_eq!(result.config.number, 123);
+    assert_eq!(result.config.list1, vec!["1", "2", "3"]);
+    assert_eq!(result.config.nested.string, "foo");
+}
+
+#[test]
+fn warns_for_deprecated_env_var_from_provider() {
+    let result = ConfigLoader::<EnvVarsMultiple>::new()
+        .set_env_provider(EnvSnapshot::from_iter([("ENV_TOKEN", "old")]))
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.token, "old");
+    assert_eq!(result.warnings.len(), 1);
+}
+
+#[test]
+fn doesnt_warn_when_file_env_var_takes_precedence() {
+    let sandbox = starbase_sandbox::create_empty_sandbox();
+    sandbox.create_file("token", "new");
+
+    let result = ConfigLoader::<EnvVarsMultiple>::new()
+        .set_env_provider(EnvSnapshot::from_iter([
+            (
+                "ENV_GITHUB_TOKEN_FILE",
+                sandbox.path().join("token").to_str().unwrap(),
+            ),
+            ("ENV_TOKEN", "old"),
+        ]))
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.token, "new");
+    assert!(result.warnings.is_empty());
+}
+
+#[derive(Debug, Config)]
+pub struct EnvVarsMultipleParent {
+    #[setting(nested)]
+    child: EnvVarsMultiple,
+}
+
+#[test]
+fn warns_for_deprecated_nested_env_var() {
+    let result = ConfigLoader::<EnvVarsMultipleParent>::new()
+        .set_env_provider(EnvSnapshot::from_iter([("ENV_TOKEN", "old")]))
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.child.token, "old");
+    assert_eq!(
+        result.warnings[0].to_full_string(),
+        "Environment variable ENV_TOKEN for setting child.token is deprecated. Use ENV_API_TOKEN instead."
+    );
+}
+
+#[test]
+#[serial]
+fn captures_snapshot_with_prefix() {
+    env::set_var("ENV_STRING", "foo");
+    env::set_var("OTHER_NUMBER", "123");
+
+    let snapshot = EnvSnapshot::capture_with_prefix("ENV_");
+
+    env::set_var("ENV_STRING", "changed");
+    env::remove_var("OTHER_NUMBER");
+
+    let result = ConfigLoader::<EnvVars>::new()
+        .set_env_provider(snapshot.clone())
+        .load()
+        .unwrap();
+
+    reset_vars();
+
+    assert_eq!(result.config.string, "foo");
+    assert_eq!(snapshot.get("OTHER_NUMBER"), None);
+}
+
+#[test]
+fn warns_for_unknown_prefixed_env_vars() {
+    let vars = HashMap::from_iter([
+        ("ENV_STRING".to_owned(), "foo".to_owned()),
+        ("ENV_NUMBR".to_owned(), "123".to_owned()),
+        ("ENV_PATH_FILE".to_owned(), "missing".to_owned()),
+        ("ENV_PATH".to_owned(), "some/path".to_owned()),
+        ("ENV_UNRELATED_THING".to_owned(), "abc".to_owned()),
+        ("OTHER_NUMBR".to_owned(), "123".to_owned()),
+    ]);
+
+    let result = ConfigLoader::<EnvVarsPrefixed>::new()
+        .set_env_provider(vars)
+        .load()
+        .unwrap();
+
+    assert_eq!(
+        result
+            .warnings
+            .iter()
+            .map(|warning| warning.to_full_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "Unknown environment variable ENV_NUMBR was ignored. Did you mean `ENV_NUMBER`?",
+            "Unknown environment variable ENV_UNRELATED_THING was ignored.",
+        ]
+    );
+}
+
+#[test]
+fn doesnt_warn_for_profile_env_var() {
+    let result = ConfigLoader::<EnvVarsPrefixed>::new()
+        .set_env_provider(HashMap::from_iter([(
+            "ENV_PROFILE".to_owned(),
+            "dev".to_owned(),
+        )]))
+        .profile_from_env("ENV_PROFILE")
+        .load()
+        .unwrap();
+
+    assert!(result.warnings.is_empty());
+}
@@ -14,6 +14,169 @@ pub struct BaseConfig {
     nested: NestedConfig,
 }
 
+#[cfg(feature = "hcl")]
+mod hcl {
+    use super::*;
+
+    #[test]
+    fn invalid_type() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("setting = 123", Format::Hcl)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. setting: invalid type: integer `123`, expected a boolean"
+        )
+    }
+
+    #[test]
+    fn invalid_nested_type() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("nested {\n  setting = 123\n}", Format::Hcl)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. nested.setting: invalid type: integer `123`, expected a boolean"
+        )
+    }
+
+    #[test]
+    fn invalid_syntax() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("nested {\n  setting = true\n", Format::Hcl)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. : invalid block body; expected `}`, newline or identifier"
+        )
+    }
+
+    #[test]
+    fn invalid_expression_type() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("setting = var.enabled", Format::Hcl)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. setting: invalid type: string \"${var.enabled}\", expected a boolean"
+        )
+    }
+
+    #[test]
+    fn includes_key_span() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("nested {\n  setting = 123\n}", Format::Hcl)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        let ConfigError::Parser { error, .. } = error else {
+            panic!("Expected a parser error.");
+        };
+
+        assert_eq!(error.span, Some((11, 7).into()));
+    }
+}
+
+#[cfg(feature = "ini")]
+mod ini {
+    use super::*;
+
+    #[test]
+    fn invalid_type() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("setting = 123", Format::Ini)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. setting: invalid value: string \"123\", expected a boolean"
+        )
+    }
+
+    #[test]
+    fn invalid_nested_type() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("[nested]\nsetting = maybe", Format::Ini)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. nested.setting: invalid value: string \"maybe\", expected a boolean"
+        )
+    }
+
+    #[test]
+    fn invalid_syntax() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("[nested\nsetting = true", Format::Ini)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. : Unclosed section header `[nested`."
+        )
+    }
+
+    #[test]
+    fn includes_value_span() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("[nested]\nsetting = maybe", Format::Ini)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        let ConfigError::Parser { error, .. } = error else {
+            panic!("Expected a parser error.");
+        };
+
+        assert_eq!(error.span, Some((19, 0).into()));
+    }
+
+    #[test]
+    fn includes_value_span_when_value_matches_key() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("  setting  =  setting  ", Format::Ini)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        let ConfigError::Parser { error, .. } = error else {
+            panic!("Expected a parser error.");
+        };
+
+        assert_eq!(error.span, Some((14, 0).into()));
+    }
+}
+
 #[cfg(feature = "json")]
 mod json {
     use super::*;
@@ -49,6 +212,171 @@ mod json {
     }
 }
 
+#[cfg(feature = "json5")]
+mod json5 {
+    use super::*;
+
+    #[test]
+    fn invalid_type() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("{ setting: 123 }", Format::Json5)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. setting: invalid type: integer `123`, expected a boolean"
+        )
+    }
+
+    #[test]
+    fn invalid_nested_type() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("// comment\n{ nested: { setting: 123 } }", Format::Json5)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. nested.setting: invalid type: integer `123`, expected a boolean"
+        )
+    }
+
+    #[test]
+    fn invalid_syntax() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("{ setting: tru }", Format::Json5)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. : expected array, boolean, null, number, object, or string at line 1 column 12"
+        )
+    }
+}
+
+#[cfg(feature = "properties")]
+mod properties {
+    use super::*;
+
+    #[test]
+    fn invalid_type() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("setting=123", Format::Properties)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. setting: invalid value: string \"123\", expected a boolean"
+        )
+    }
+
+    #[test]
+    fn invalid_nested_type() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("nested.setting=maybe", Format::Properties)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. nested.setting: invalid value: string \"maybe\", expected a boolean"
+        )
+    }
+
+    #[test]
+    fn invalid_syntax() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("setting=true\nsetting.nested=true", Format::Properties)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. setting: Key `setting` is a value and cannot contain nested keys."
+        )
+    }
+    #[test]
+    fn includes_value_span_on_continued_line() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("nested.setting = \\\n    may\\\n  be", Format::Properties)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        let ConfigError::Parser { error, .. } = error else {
+            panic!("Expected a parser error.");
+        };
+
+        assert_eq!(error.span, Some((23, 0).into()));
+    }
+}
+
+#[cfg(feature = "ron")]
+mod ron {
+    use super::*;
+
+    #[test]
+    fn invalid_type() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("(setting: 123)", Format::Ron)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. setting: Expected boolean"
+        )
+    }
+
+    #[test]
+    fn invalid_nested_type() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("(\n  nested: (setting: 123),\n)", Format::Ron)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. nested.setting: Expected boolean"
+        )
+    }
+
+    #[test]
+    fn invalid_syntax() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .code("(setting: true", Format::Ron)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_full_string(),
+            "Failed to parse BaseConfig. ?: Unexpected end of RON"
+        )
+    }
+}
+
 #[cfg(feature = "json")]
 mod toml {
     use super::*;
@@ -117,4 +445,22 @@ mod yaml {
             "Failed to parse BaseConfig. nested.setting: invalid type: integer `123`, expected a boolean"
         )
     }
+
+    #[test]
+    fn retains_positions_within_document() {
+        let error = ConfigLoader::<BaseConfig>::new()
+            .split_documents()
+            .code("setting: true\n---\nsetting: 123", Format::Yaml)
+            .unwrap()
+            .load()
+            .err()
+            .unwrap();
+
+        let ConfigError::Parser { error, .. } = error else {
+            panic!("Expected a parser error.");
+        };
+
+        assert_eq!(error.path, "setting");
+        assert_eq!(error.content, "             \n---\nsetting: 123");
+    }
 }
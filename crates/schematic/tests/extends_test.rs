@@ -178,6 +178,45 @@ fn extends_from_chain_in_order_using_both_enum() {
     );
 }
 
+#[cfg(all(feature = "ini", feature = "properties"))]
+#[test]
+fn extends_from_ini_and_properties() {
+    let root = get_fixture_path("extending");
+
+    let result = ConfigLoader::<ExtendsEnum>::new()
+        .file(root.join("base.ini"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.value, vec![3, 2, 4, 1]);
+
+    assert_eq!(
+        result
+            .layers
+            .into_iter()
+            .map(|l| l.source)
+            .collect::<Vec<_>>(),
+        vec![
+            Source::File {
+                path: root.join("./string2.yml"),
+                format: Format::Yaml,
+                required: true,
+            },
+            Source::File {
+                path: root.join("./list.properties"),
+                format: Format::Properties,
+                required: true,
+            },
+            Source::File {
+                path: root.join("./base.ini"),
+                format: Format::Ini,
+                required: true,
+            },
+        ]
+    );
+}
+
 #[test]
 fn extends_from_optional() {
     let root = get_fixture_path("extending");
@@ -112,6 +112,149 @@ fn can_create_file_source_with_parent() {
     );
 }
 
+#[cfg(feature = "hcl")]
+#[test]
+fn loads_hcl_files() {
+    let root = get_fixture_path("hcl");
+
+    let result = ConfigLoader::<Config>::new()
+        .file(root.join("one.hcl"))
+        .unwrap()
+        .file(root.join("two.hcl"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(result.config.boolean);
+    assert_eq!(result.config.string, "bar");
+    assert_eq!(result.config.number, 123);
+    assert_eq!(result.config.vector, vec!["a", "b", "c"]);
+}
+
+#[cfg(feature = "hcl")]
+#[test]
+fn loads_hcl_expressions_as_strings() {
+    let root = get_fixture_path("hcl");
+
+    let result = ConfigLoader::<Config>::new()
+        .file(root.join("expressions.hcl"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.string, "${upper(var.name)}-suffix");
+    assert_eq!(result.config.number, 3);
+    assert_eq!(
+        result.config.vector,
+        vec![
+            "${var.a}",
+            "${[for item in var.items : item.name]}",
+            "heredoc\n"
+        ]
+    );
+}
+
+#[derive(Debug, Config)]
+pub struct BlockItem {
+    name: String,
+}
+
+#[derive(Debug, Config)]
+pub struct BlockServer {
+    port: usize,
+}
+
+#[derive(Debug, Config)]
+pub struct BlocksConfig {
+    #[setting(nested)]
+    items: Vec<BlockItem>,
+    #[setting(nested)]
+    server: BlockServer,
+}
+
+#[cfg(feature = "hcl")]
+#[test]
+fn loads_hcl_single_block_as_list() {
+    let root = get_fixture_path("hcl");
+
+    let result = ConfigLoader::<BlocksConfig>::new()
+        .file(root.join("single-block.hcl"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.server.port, 8080);
+    assert_eq!(result.config.items.len(), 1);
+    assert_eq!(result.config.items[0].name, "a");
+}
+
+#[cfg(feature = "hcl")]
+#[test]
+fn loads_hcl_repeated_blocks() {
+    let root = get_fixture_path("hcl");
+
+    let result = ConfigLoader::<BlocksConfig>::new()
+        .file(root.join("repeated-blocks.hcl"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.items.len(), 2);
+    assert_eq!(result.config.items[0].name, "a");
+    assert_eq!(result.config.items[1].name, "b");
+}
+
+#[cfg(feature = "ini")]
+#[test]
+fn loads_ini_files() {
+    let root = get_fixture_path("ini");
+
+    let result = ConfigLoader::<Config>::new()
+        .file(root.join("one.ini"))
+        .unwrap()
+        .file(root.join("two.ini"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(result.config.boolean);
+    assert_eq!(result.config.string, "foo");
+    assert_eq!(result.config.number, 123);
+    assert_eq!(result.config.vector, vec!["a", "b", "c"]);
+}
+
+#[cfg(feature = "ini")]
+#[test]
+fn strips_inline_ini_comments() {
+    let result = ConfigLoader::<Config>::new()
+        .code(
+            "number = 8080 ; default\nstring = \"a ; b # c\" # quoted\nvector = a#b, c;d",
+            Format::Ini,
+        )
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.string, "a ; b # c");
+    assert_eq!(result.config.number, 8080);
+    assert_eq!(result.config.vector, vec!["a#b", "c;d"]);
+}
+
+#[cfg(feature = "ini")]
+#[test]
+fn treats_empty_ini_values_as_unset() {
+    let result = ConfigLoader::<Config>::new()
+        .code("number = 123\nstring = foo", Format::Ini)
+        .unwrap()
+        .code("number =\nstring =", Format::Ini)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.string, "foo");
+    assert_eq!(result.config.number, 123);
+}
+
 #[cfg(feature = "json")]
 #[test]
 fn loads_json_files() {
@@ -153,6 +296,99 @@ fn loads_json_file_optional() {
     assert_eq!(result.config.number, 0);
 }
 
+#[cfg(feature = "json5")]
+#[test]
+fn loads_json5_files() {
+    let root = get_fixture_path("json5");
+
+    let result = ConfigLoader::<Config>::new()
+        .file(root.join("one.json5"))
+        .unwrap()
+        .file(root.join("two.jsonc"))
+        .unwrap()
+        .file(root.join("three.json5"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(result.config.boolean);
+    assert_eq!(result.config.string, "bar");
+    assert_eq!(result.config.number, 123);
+    assert_eq!(result.config.vector, vec!["a", "b", "c"]);
+}
+
+#[cfg(feature = "properties")]
+#[test]
+fn loads_properties_files() {
+    let root = get_fixture_path("properties");
+
+    let result = ConfigLoader::<Config>::new()
+        .file(root.join("one.properties"))
+        .unwrap()
+        .file(root.join("two.properties"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(result.config.boolean);
+    assert_eq!(result.config.string, "foo");
+    assert_eq!(result.config.number, 123);
+    assert_eq!(result.config.vector, vec!["a", "b", "c"]);
+}
+
+#[cfg(feature = "properties")]
+#[test]
+fn treats_empty_properties_values_as_unset() {
+    let result = ConfigLoader::<Config>::new()
+        .code("number=123", Format::Properties)
+        .unwrap()
+        .code("number", Format::Properties)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.number, 123);
+}
+
+#[derive(Debug, Config)]
+pub struct OrderedConfig {
+    map: indexmap::IndexMap<String, String>,
+}
+
+#[cfg(feature = "properties")]
+#[test]
+fn retains_properties_key_order() {
+    let result = ConfigLoader::<OrderedConfig>::new()
+        .code("map.c=1\nmap.a=2\nmap.b=3", Format::Properties)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(
+        result.config.map.keys().collect::<Vec<_>>(),
+        vec!["c", "a", "b"]
+    );
+}
+
+#[cfg(feature = "ron")]
+#[test]
+fn loads_ron_files() {
+    let root = get_fixture_path("ron");
+
+    let result = ConfigLoader::<Config>::new()
+        .file(root.join("one.ron"))
+        .unwrap()
+        .file(root.join("two.ron"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(result.config.boolean);
+    assert_eq!(result.config.string, "bar");
+    assert_eq!(result.config.number, 123);
+    assert_eq!(result.config.vector, vec!["a", "b", "c"]);
+}
+
 #[cfg(feature = "toml")]
 #[test]
 fn loads_toml_files() {
@@ -218,6 +454,38 @@ fn loads_yaml_files() {
     assert_eq!(result.config.vector, vec!["x", "y", "z"]);
 }
 
+#[cfg(feature = "yaml")]
+#[test]
+fn loads_yaml_documents_as_layers() {
+    let root = get_fixture_path("yaml");
+
+    let result = ConfigLoader::<Config>::new()
+        .split_documents()
+        .file(root.join("documents.yml"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(result.config.boolean);
+    assert_eq!(result.config.string, "bar");
+    assert_eq!(result.config.number, 123);
+    assert_eq!(result.config.vector, vec!["a", "b", "c"]);
+    assert_eq!(result.layers.len(), 3);
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn errors_for_yaml_documents_without_splitting() {
+    let root = get_fixture_path("yaml");
+
+    let result = ConfigLoader::<Config>::new()
+        .file(root.join("documents.yml"))
+        .unwrap()
+        .load();
+
+    assert!(result.is_err());
+}
+
 #[cfg(feature = "yaml")]
 #[test]
 fn loads_yaml_file_optional() {
@@ -0,0 +1,159 @@
+#![allow(dead_code)]
+
+mod utils;
+
+use crate::utils::get_fixture_path;
+use schematic::*;
+use std::path::PathBuf;
+
+#[derive(Debug, Config)]
+pub struct Config {
+    #[setting(extend)]
+    extends: Option<String>,
+    boolean: bool,
+    string: String,
+    number: usize,
+    vector: Vec<String>,
+}
+
+#[test]
+fn checks_for_extensions() {
+    assert!(has_extension("config.yml"));
+    assert!(has_extension("./some/path/config.json"));
+    assert!(has_extension("https://domain.com/config.toml?id=1"));
+    assert!(!has_extension(".apprc"));
+    assert!(!has_extension("./some/path/config"));
+    assert!(!has_extension("https://domain.com/config?id=1.2"));
+    assert!(!has_extension("https://domain.com"));
+}
+
+#[test]
+fn detects_from_content() {
+    let detect = |content: &str| Format::detect_from_content("test", content).unwrap();
+
+    assert_eq!(detect(r#"{ "key": "value" }"#), Format::Json5);
+    assert_eq!(detect("// comment\n{ key: 'value' }"), Format::Json5);
+    assert_eq!(detect("# comment\n\n  {\n  \"key\": 1\n}"), Format::Json5);
+    assert_eq!(detect("---\nkey: value"), Format::Yaml);
+    assert_eq!(detect("# comment\nkey: value"), Format::Yaml);
+    assert_eq!(detect("- a\n- b"), Format::Yaml);
+    assert_eq!(detect("[table]\nkey = 1"), Format::Toml);
+    assert_eq!(detect("# comment\nkey = \"a:b\""), Format::Toml);
+    assert_eq!(detect("url: https://domain.com?a=b"), Format::Yaml);
+    assert_eq!(detect(""), Format::Yaml);
+}
+
+#[test]
+fn errors_for_undetectable_content() {
+    let error = Format::detect_from_content("test", "???").unwrap_err();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Unable to detect the format of test from its content, expected JSON5, JSON, YAML, TOML."
+    );
+}
+
+#[test]
+fn converts_content_types() {
+    assert_eq!(
+        Format::from_content_type("application/json; charset=utf-8"),
+        Some(Format::Json)
+    );
+    assert_eq!(
+        Format::from_content_type("application/ld+json"),
+        Some(Format::Json)
+    );
+    assert_eq!(
+        Format::from_content_type("application/toml"),
+        Some(Format::Toml)
+    );
+    assert_eq!(
+        Format::from_content_type("application/x-yaml"),
+        Some(Format::Yaml)
+    );
+    assert_eq!(Format::from_content_type("text/yaml"), Some(Format::Yaml));
+    assert_eq!(Format::from_content_type("text/plain"), None);
+}
+
+#[test]
+fn defers_detection_for_extensionless_sources() {
+    assert_eq!(
+        Source::new("./.apprc", None).unwrap(),
+        Source::File {
+            path: PathBuf::from("./.apprc"),
+            format: Format::None,
+            required: true,
+        }
+    );
+
+    assert_eq!(
+        Source::new("https://domain.com/config?id=1", None).unwrap(),
+        Source::Url {
+            url: "https://domain.com/config?id=1".into(),
+            format: Format::None,
+        }
+    );
+
+    assert!(Source::new("./config.unknown", None).is_err());
+}
+
+#[test]
+fn loads_and_extends_extensionless_files() {
+    let root = get_fixture_path("detect");
+
+    let result = ConfigLoader::<Config>::new()
+        .file(root.join("config"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(result.config.boolean);
+    assert_eq!(result.config.string, "foo");
+    assert_eq!(result.config.number, 123);
+    assert_eq!(result.config.vector, vec!["a", "b", "c"]);
+
+    // Layers retain the detected format
+    assert_eq!(
+        result
+            .layers
+            .into_iter()
+            .map(|layer| layer.source)
+            .collect::<Vec<_>>(),
+        vec![
+            Source::File {
+                path: root.join("./.apprc"),
+                format: Format::Yaml,
+                required: true,
+            },
+            Source::File {
+                path: root.join("config"),
+                format: Format::Toml,
+                required: true,
+            },
+        ]
+    );
+}
+
+#[test]
+fn loads_files_with_explicit_format() {
+    let root = get_fixture_path("detect");
+
+    let result = ConfigLoader::<Config>::new()
+        .file_with_format(root.join("settings.conf"), Format::Json)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.string, "bar");
+}
+
+#[test]
+fn detects_code_format() {
+    let result = ConfigLoader::<Config>::new()
+        .code("string = \"baz\"", Format::None)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.string, "baz");
+}
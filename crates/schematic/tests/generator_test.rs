@@ -236,6 +236,24 @@ mod json_schema {
     }
 }
 
+#[cfg(all(feature = "renderer_template", feature = "hcl"))]
+mod template_hcl {
+    use super::*;
+    use schematic::schema::*;
+
+    #[test]
+    fn defaults() {
+        let sandbox = create_empty_sandbox();
+        let file = sandbox.path().join("schema.hcl");
+
+        create_template_generator()
+            .generate(&file, HclTemplateRenderer::new(create_template_options()))
+            .unwrap();
+
+        assert_snapshot!(fs::read_to_string(file).unwrap());
+    }
+}
+
 #[cfg(all(feature = "renderer_template", feature = "json"))]
 mod template_json {
     use super::*;
@@ -266,6 +284,24 @@ mod template_json {
     }
 }
 
+#[cfg(all(feature = "renderer_template", feature = "ron"))]
+mod template_ron {
+    use super::*;
+    use schematic::schema::*;
+
+    #[test]
+    fn defaults() {
+        let sandbox = create_empty_sandbox();
+        let file = sandbox.path().join("schema.ron");
+
+        create_template_generator()
+            .generate(&file, RonTemplateRenderer::new(create_template_options()))
+            .unwrap();
+
+        assert_snapshot!(fs::read_to_string(file).unwrap());
+    }
+}
+
 #[cfg(all(feature = "renderer_template", feature = "toml"))]
 mod template_toml {
     use super::*;
@@ -0,0 +1,193 @@
+#![allow(dead_code)]
+
+mod utils;
+
+use crate::utils::get_fixture_path;
+use schematic::*;
+
+#[derive(Debug, Config)]
+pub struct ServerConfig {
+    host: String,
+    port: usize,
+}
+
+#[derive(Debug, Config)]
+pub struct PluginOptions {
+    debug: bool,
+}
+
+#[derive(Debug, Config)]
+pub struct PluginConfig {
+    name: String,
+    #[setting(nested)]
+    options: PluginOptions,
+}
+
+#[derive(Debug, Config)]
+pub struct IncludeConfig {
+    name: String,
+    #[setting(nested)]
+    server: ServerConfig,
+    #[setting(nested)]
+    plugins: Vec<PluginConfig>,
+}
+
+#[test]
+fn includes_nested_sources() {
+    let root = get_fixture_path("includes");
+
+    let result = ConfigLoader::<IncludeConfig>::new()
+        .resolve_includes()
+        .file(root.join("root.yml"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.name, "app");
+    assert_eq!(result.config.server.host, "localhost");
+    assert_eq!(result.config.server.port, 8080);
+    assert_eq!(result.config.plugins.len(), 2);
+    assert_eq!(result.config.plugins[0].name, "one");
+    assert!(result.config.plugins[0].options.debug);
+    assert_eq!(result.config.plugins[1].name, "two");
+    assert!(!result.config.plugins[1].options.debug);
+    assert_eq!(result.layers.len(), 1);
+}
+
+#[test]
+fn includes_ini_sources() {
+    let root = get_fixture_path("includes");
+
+    let result = ConfigLoader::<IncludeConfig>::new()
+        .resolve_includes()
+        .file(root.join("root.ini"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.name, "app");
+    assert_eq!(result.config.server.host, "localhost");
+    assert_eq!(result.config.server.port, 8080);
+}
+
+#[test]
+fn overrides_included_settings() {
+    let root = get_fixture_path("includes");
+
+    let result = ConfigLoader::<IncludeConfig>::new()
+        .resolve_includes()
+        .file(root.join("override.json"))
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.server.host, "localhost");
+    assert_eq!(result.config.server.port, 3000);
+}
+
+#[test]
+fn errors_when_not_enabled() {
+    let root = get_fixture_path("includes");
+
+    let result = ConfigLoader::<IncludeConfig>::new()
+        .file(root.join("override.json"))
+        .unwrap()
+        .load();
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn errors_for_circular_includes() {
+    let root = get_fixture_path("includes");
+
+    let error = ConfigLoader::<IncludeConfig>::new()
+        .resolve_includes()
+        .file(root.join("cycle.yml"))
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    let ConfigError::IncludeCycle {
+        include,
+        path,
+        span,
+        ..
+    } = error
+    else {
+        panic!("Expected a cycle error.");
+    };
+
+    assert_eq!(include, "./cycle.yml");
+    assert_eq!(path, "server.host");
+    assert_eq!(span, Some((15, 11).into()));
+}
+
+#[test]
+fn errors_for_missing_includes() {
+    let root = get_fixture_path("includes");
+
+    let error = ConfigLoader::<IncludeConfig>::new()
+        .resolve_includes()
+        .file(root.join("missing.yml"))
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    let ConfigError::IncludeFailed { error, .. } = error else {
+        panic!("Expected an include error.");
+    };
+
+    assert!(matches!(*error, ConfigError::MissingFile(_)));
+}
+
+#[test]
+fn points_errors_to_included_source() {
+    let root = get_fixture_path("includes");
+
+    let error = ConfigLoader::<IncludeConfig>::new()
+        .resolve_includes()
+        .file(root.join("invalid.yml"))
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    let ConfigError::Parser { config, error, .. } = error else {
+        panic!("Expected a parser error.");
+    };
+
+    assert!(config.ends_with("invalid-server.yml"));
+    assert_eq!(error.path, "server.port");
+    assert_eq!(error.content, "host: localhost\nport: abc\n");
+    assert_eq!(error.span, Some((16, 4).into()));
+}
+
+#[test]
+fn errors_for_duplicate_keys_in_included_source() {
+    let root = get_fixture_path("includes");
+
+    let error = ConfigLoader::<IncludeConfig>::new()
+        .deny_duplicate_keys()
+        .resolve_includes()
+        .file(root.join("duplicate.yml"))
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    let ConfigError::IncludeFailed { error, .. } = error else {
+        panic!("Expected an include error.");
+    };
+
+    let ConfigError::Parser { config, error, .. } = *error else {
+        panic!("Expected a parser error.");
+    };
+
+    assert!(config.ends_with("duplicate-server.yml"));
+    assert_eq!(error.path, "port");
+    assert_eq!(error.first_span, Some((16, 4).into()));
+    assert_eq!(error.span, Some((27, 4).into()));
+}
@@ -0,0 +1,270 @@
+use schematic::*;
+use serial_test::serial;
+use starbase_sandbox::create_empty_sandbox;
+use std::env;
+
+#[derive(Debug, Config)]
+pub struct InterpolateConfig {
+    pub host: String,
+    pub port: usize,
+    pub debug: bool,
+    pub list: Vec<String>,
+    pub token: Option<String>,
+}
+
+#[test]
+fn doesnt_interpolate_when_disabled() {
+    let result = ConfigLoader::<InterpolateConfig>::new()
+        .code("host: ${SCHEMATIC_HOST}", Format::Yaml)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.host, "${SCHEMATIC_HOST}");
+}
+
+#[test]
+#[serial]
+fn interpolates_env_vars() {
+    env::set_var("SCHEMATIC_HOST", "example.com");
+    env::set_var("SCHEMATIC_ITEM", "b");
+
+    let result = ConfigLoader::<InterpolateConfig>::new()
+        .code(
+            "host: https://${SCHEMATIC_HOST}/api\nlist: [a, '${env:SCHEMATIC_ITEM}']",
+            Format::Yaml,
+        )
+        .unwrap()
+        .interpolate()
+        .load()
+        .unwrap();
+
+    env::remove_var("SCHEMATIC_HOST");
+    env::remove_var("SCHEMATIC_ITEM");
+
+    assert_eq!(result.config.host, "https://example.com/api");
+    assert_eq!(result.config.list, vec!["a", "b"]);
+}
+
+#[test]
+#[serial]
+fn coerces_to_setting_type() {
+    env::set_var("SCHEMATIC_PORT", "8080");
+    env::set_var("SCHEMATIC_DEBUG", "true");
+
+    let result = ConfigLoader::<InterpolateConfig>::new()
+        .code(
+            r#"{ "port": "${SCHEMATIC_PORT}", "debug": "${SCHEMATIC_DEBUG}" }"#,
+            Format::Json,
+        )
+        .unwrap()
+        .interpolate()
+        .load()
+        .unwrap();
+
+    env::remove_var("SCHEMATIC_PORT");
+    env::remove_var("SCHEMATIC_DEBUG");
+
+    assert_eq!(result.config.port, 8080);
+    assert!(result.config.debug);
+}
+
+#[test]
+#[serial]
+fn uses_fallback_when_unset_or_empty() {
+    env::set_var("SCHEMATIC_EMPTY", "");
+
+    let result = ConfigLoader::<InterpolateConfig>::new()
+        .code(
+            "host: ${SCHEMATIC_MISSING:-localhost}\nport: ${SCHEMATIC_EMPTY:-3000}",
+            Format::Yaml,
+        )
+        .unwrap()
+        .interpolate()
+        .load()
+        .unwrap();
+
+    env::remove_var("SCHEMATIC_EMPTY");
+
+    assert_eq!(result.config.host, "localhost");
+    assert_eq!(result.config.port, 3000);
+}
+
+#[test]
+fn supports_braces_in_fallback() {
+    let result = ConfigLoader::<InterpolateConfig>::new()
+        .code(
+            r#"{ "host": "${SCHEMATIC_MISSING:-{\"a\":{\"b\":1}}}/api", "token": "${SCHEMATIC_MISSING:-}}" }"#,
+            Format::Json,
+        )
+        .unwrap()
+        .set_env_provider(EnvSnapshot::default())
+        .interpolate()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.host, r#"{"a":{"b":1}}/api"#);
+    assert_eq!(result.config.token.as_deref(), Some("}"));
+}
+
+#[test]
+fn reads_env_vars_from_provider() {
+    let result = ConfigLoader::<InterpolateConfig>::new()
+        .code(
+            "host: ${SCHEMATIC_PROVIDED_HOST}\nlist: ['${env:SCHEMATIC_PROVIDED_ITEM}']",
+            Format::Yaml,
+        )
+        .unwrap()
+        .set_env_provider(EnvSnapshot::from_iter([
+            ("SCHEMATIC_PROVIDED_HOST", "example.com"),
+            ("SCHEMATIC_PROVIDED_ITEM", "a"),
+        ]))
+        .interpolate()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.host, "example.com");
+    assert_eq!(result.config.list, vec!["a"]);
+}
+
+#[test]
+fn interpolates_ini_values() {
+    let result = ConfigLoader::<InterpolateConfig>::new()
+        .code(
+            "host = ${SCHEMATIC_INI_HOST:-localhost}\nport = ${SCHEMATIC_INI_PORT}\ndebug = true\nlist = a, b",
+            Format::Ini,
+        )
+        .unwrap()
+        .set_env_provider(EnvSnapshot::from_iter([("SCHEMATIC_INI_PORT", "8080")]))
+        .interpolate()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.host, "localhost");
+    assert_eq!(result.config.port, 8080);
+    assert!(result.config.debug);
+    assert_eq!(result.config.list, vec!["a", "b"]);
+}
+
+#[test]
+fn supports_escaping() {
+    let result = ConfigLoader::<InterpolateConfig>::new()
+        .code("host: $${SCHEMATIC_HOST} costs $5", Format::Yaml)
+        .unwrap()
+        .interpolate()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.host, "${SCHEMATIC_HOST} costs $5");
+}
+
+#[test]
+fn resolves_files_relative_to_source() {
+    let sandbox = create_empty_sandbox();
+    sandbox.create_file("secret.txt", "abc123\n");
+    sandbox.create_file("config.yml", "token: ${file:./secret.txt}");
+
+    let result = ConfigLoader::<InterpolateConfig>::new()
+        .file(sandbox.path().join("config.yml"))
+        .unwrap()
+        .interpolate()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.token.unwrap(), "abc123");
+}
+
+#[test]
+fn supports_custom_resolvers() {
+    let mut interpolator = Interpolator::default();
+    interpolator.add_resolver("upper", |value: &str, _: &Source| {
+        Ok(Some(value.to_uppercase()))
+    });
+
+    let result = ConfigLoader::<InterpolateConfig>::new()
+        .code("host: ${upper:localhost}", Format::Yaml)
+        .unwrap()
+        .set_interpolator(interpolator)
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.host, "LOCALHOST");
+}
+
+#[test]
+fn errors_for_unresolved_variable() {
+    let error = ConfigLoader::<InterpolateConfig>::new()
+        .code(
+            "host: localhost\nlist: [a, '${SCHEMATIC_UNKNOWN}']",
+            Format::Yaml,
+        )
+        .unwrap()
+        .interpolate()
+        .load()
+        .err()
+        .unwrap();
+
+    let ConfigError::UnresolvedVariable {
+        name, path, span, ..
+    } = &error
+    else {
+        panic!("expected unresolved variable error");
+    };
+
+    assert_eq!(name, "${SCHEMATIC_UNKNOWN}");
+    assert_eq!(path, "list[1]");
+    assert_eq!(span.unwrap().offset(), 27);
+}
+
+#[test]
+fn errors_for_unknown_resolver() {
+    let error = ConfigLoader::<InterpolateConfig>::new()
+        .code("host: ${secret:key}", Format::Yaml)
+        .unwrap()
+        .interpolate()
+        .load()
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Unable to resolve variable ${secret:key} for setting host in InterpolateConfig. Unknown variable resolver `secret`."
+    );
+}
+
+#[test]
+fn errors_for_unterminated_variable() {
+    let error = ConfigLoader::<InterpolateConfig>::new()
+        .code("host: ${SCHEMATIC_HOST", Format::Yaml)
+        .unwrap()
+        .interpolate()
+        .load()
+        .err()
+        .unwrap();
+
+    assert!(matches!(error, ConfigError::UnresolvedVariable { .. }));
+}
+
+#[test]
+fn errors_for_files_in_non_file_sources() {
+    let sandbox = create_empty_sandbox();
+    sandbox.create_file("secret.txt", "abc123");
+
+    let error = ConfigLoader::<InterpolateConfig>::new()
+        .code(
+            format!(
+                "token: ${{file:{}}}",
+                sandbox.path().join("secret.txt").display()
+            ),
+            Format::Yaml,
+        )
+        .unwrap()
+        .interpolate()
+        .load()
+        .err()
+        .unwrap();
+
+    assert!(error
+        .to_full_string()
+        .ends_with("File variables are only supported within file sources."));
+}
@@ -0,0 +1,168 @@
+use schematic::*;
+use std::path::PathBuf;
+
+#[derive(Debug, Config)]
+pub struct OverridesConfig {
+    #[setting(default = 2)]
+    pub indent: usize,
+    pub quotes: Option<String>,
+    #[setting(merge = merge::append_vec)]
+    pub rules: Vec<String>,
+    #[setting(overrides, merge = merge::append_vec)]
+    pub overrides: Vec<PathOverride<PartialOverridesConfig>>,
+}
+
+const CODE: &str = r#"
+rules: [base]
+overrides:
+  - files: ["src/**/*.ts"]
+    settings:
+      indent: 4
+      rules: [ts]
+  - files: ["*.test.ts", "tests/**"]
+    settings:
+      quotes: single
+      rules: [test]
+"#;
+
+fn load() -> (
+    ConfigLoader<OverridesConfig>,
+    ConfigLoadResult<OverridesConfig>,
+) {
+    let mut loader = ConfigLoader::<OverridesConfig>::new();
+    loader.code(CODE, Format::Yaml).unwrap();
+
+    let result = loader.load().unwrap();
+
+    (loader, result)
+}
+
+#[test]
+fn doesnt_apply_overrides_to_base() {
+    let (_, result) = load();
+
+    assert_eq!(result.config.indent, 2);
+    assert_eq!(result.config.rules, vec!["base"]);
+    assert_eq!(result.config.overrides.len(), 2);
+}
+
+#[test]
+fn returns_base_when_no_match() {
+    let (loader, result) = load();
+    let config = loader.resolve_for_path(&result, "lib/index.js").unwrap();
+
+    assert_eq!(config.indent, 2);
+    assert_eq!(config.quotes, None);
+    assert_eq!(config.rules, vec!["base"]);
+}
+
+#[test]
+fn applies_matching_override() {
+    let (loader, result) = load();
+    let config = loader
+        .resolve_for_path(&result, "src/app/index.ts")
+        .unwrap();
+
+    assert_eq!(config.indent, 4);
+    assert_eq!(config.quotes, None);
+    assert_eq!(config.rules, vec!["base", "ts"]);
+}
+
+#[test]
+fn applies_multiple_overrides_in_order() {
+    let (loader, result) = load();
+    let config = loader
+        .resolve_for_path(&result, "src/app/index.test.ts")
+        .unwrap();
+
+    assert_eq!(config.indent, 4);
+    assert_eq!(config.quotes.as_deref(), Some("single"));
+    assert_eq!(config.rules, vec!["base", "ts", "test"]);
+}
+
+#[test]
+fn matches_relative_to_root() {
+    let (mut loader, result) = load();
+    loader.set_root("/workspace");
+
+    let config = loader
+        .resolve_for_path(&result, PathBuf::from("/workspace/tests/unit/a.rs"))
+        .unwrap();
+
+    assert_eq!(config.quotes.as_deref(), Some("single"));
+}
+
+#[test]
+fn merges_overrides_from_all_layers() {
+    let mut loader = ConfigLoader::<OverridesConfig>::new();
+    loader.code(CODE, Format::Yaml).unwrap();
+    loader
+        .code(
+            "overrides:\n  - files: ['src/**']\n    settings:\n      indent: 8",
+            Format::Yaml,
+        )
+        .unwrap();
+
+    let result = loader.load().unwrap();
+    let config = loader.resolve_for_path(&result, "src/index.ts").unwrap();
+
+    assert_eq!(config.indent, 8);
+    assert_eq!(config.rules, vec!["base", "ts"]);
+}
+
+#[test]
+fn errors_for_invalid_pattern() {
+    let mut loader = ConfigLoader::<OverridesConfig>::new();
+    loader
+        .code(
+            "overrides:\n  - files: ['src/[']\n    settings: {}",
+            Format::Yaml,
+        )
+        .unwrap();
+
+    let error = loader.load().err().unwrap();
+
+    assert!(error
+        .to_full_string()
+        .contains("Invalid glob pattern src/[ in path overrides."));
+}
+
+#[test]
+fn errors_for_invalid_pattern_when_created() {
+    let error = PathOverride::new(["src/["], PartialOverridesConfig::default())
+        .err()
+        .unwrap();
+
+    assert!(matches!(error, ConfigError::InvalidOverridePattern { .. }));
+}
+
+#[test]
+fn matches_created_override() {
+    let path_override =
+        PathOverride::new(["./src/**/*.ts"], PartialOverridesConfig::default()).unwrap();
+
+    assert!(path_override.matches(&PathBuf::from("src/a/b.ts")));
+    assert!(!path_override.matches(&PathBuf::from("tests/a.ts")));
+}
+
+#[test]
+fn recompiles_patterns_when_files_change() {
+    let mut path_override =
+        PathOverride::new(["src/**"], PartialOverridesConfig::default()).unwrap();
+
+    path_override.set_files(["tests/**"]).unwrap();
+
+    assert_eq!(path_override.files(), ["tests/**"]);
+    assert!(path_override.matches(&PathBuf::from("tests/a.ts")));
+    assert!(!path_override.matches(&PathBuf::from("src/a.ts")));
+}
+
+#[test]
+fn keeps_patterns_when_set_files_fails() {
+    let mut path_override =
+        PathOverride::new(["src/**"], PartialOverridesConfig::default()).unwrap();
+
+    assert!(path_override.set_files(["src/["]).is_err());
+    assert_eq!(path_override.files(), ["src/**"]);
+    assert!(path_override.matches(&PathBuf::from("src/a.ts")));
+}
@@ -0,0 +1,299 @@
+use schematic::*;
+use serial_test::serial;
+use std::env;
+
+#[derive(Debug, Config)]
+#[config(profiles)]
+pub struct ProfilesConfig {
+    #[setting(default = 3000)]
+    pub port: usize,
+    pub host: String,
+    #[setting(merge = merge::append_vec)]
+    pub list: Vec<String>,
+}
+
+const CODE: &str = r"
+host: localhost
+list: [a]
+profiles:
+  dev:
+    port: 8080
+  prod:
+    host: example.com
+    list: [b]
+";
+
+#[test]
+fn uses_base_when_no_profile() {
+    let result = ConfigLoader::<ProfilesConfig>::new()
+        .code(CODE, Format::Yaml)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.port, 3000);
+    assert_eq!(result.config.host, "localhost");
+    assert_eq!(result.config.list, vec!["a"]);
+}
+
+#[test]
+fn merges_selected_profile() {
+    let result = ConfigLoader::<ProfilesConfig>::new()
+        .code(CODE, Format::Yaml)
+        .unwrap()
+        .profile("prod")
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.port, 3000);
+    assert_eq!(result.config.host, "example.com");
+    assert_eq!(result.config.list, vec!["a", "b"]);
+}
+
+#[test]
+fn merges_profile_before_next_layer() {
+    let result = ConfigLoader::<ProfilesConfig>::new()
+        .code(CODE, Format::Yaml)
+        .unwrap()
+        .code(
+            "port: 9000\nprofiles:\n  dev:\n    host: dev.local",
+            Format::Yaml,
+        )
+        .unwrap()
+        .profile("dev")
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.port, 9000);
+    assert_eq!(result.config.host, "dev.local");
+}
+
+#[test]
+fn warns_for_missing_profile() {
+    let result = ConfigLoader::<ProfilesConfig>::new()
+        .code(CODE, Format::Yaml)
+        .unwrap()
+        .profile("staging")
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.host, "localhost");
+    assert_eq!(
+        result
+            .warnings
+            .iter()
+            .map(|warning| warning.to_full_string())
+            .collect::<Vec<_>>(),
+        ["Profile staging was selected for ProfilesConfig, but was not found in any source."]
+    );
+}
+
+#[test]
+fn doesnt_warn_when_profile_found_in_any_source() {
+    let result = ConfigLoader::<ProfilesConfig>::new()
+        .code("port: 9000", Format::Yaml)
+        .unwrap()
+        .code(CODE, Format::Yaml)
+        .unwrap()
+        .profile("dev")
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.port, 8080);
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn merges_selected_profile_from_ini() {
+    let result = ConfigLoader::<ProfilesConfig>::new()
+        .code(
+            "host = localhost\n\n[profiles.dev]\nport = 8080",
+            Format::Ini,
+        )
+        .unwrap()
+        .profile("dev")
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.port, 8080);
+    assert_eq!(result.config.host, "localhost");
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum Target {
+    Host(String),
+    Socket { path: String },
+}
+
+impl Schematic for Target {}
+
+#[derive(Debug, Config)]
+#[config(profiles)]
+pub struct RonProfilesConfig {
+    pub target: Option<Target>,
+}
+
+#[test]
+fn preserves_format_types_in_profiles() {
+    let result = ConfigLoader::<RonProfilesConfig>::new()
+        .code(
+            "(target: Host(\"localhost\"), profiles: { \"dev\": (target: Socket(path: \"/tmp/dev.sock\")) })",
+            Format::Ron,
+        )
+        .unwrap()
+        .profile("dev")
+        .load()
+        .unwrap();
+
+    assert_eq!(
+        result.config.target,
+        Some(Target::Socket {
+            path: "/tmp/dev.sock".into()
+        })
+    );
+}
+
+#[test]
+fn errors_for_invalid_setting_with_format_span() {
+    let error = ConfigLoader::<ProfilesConfig>::new()
+        .code(
+            "host = \"localhost\"\n\n[profiles.dev]\nport = \"abc\"",
+            Format::Toml,
+        )
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    let ConfigError::Parser { error, .. } = error else {
+        panic!();
+    };
+
+    assert_eq!(error.span, Some((42, 5).into()));
+}
+
+#[test]
+#[serial]
+fn selects_profile_from_env_var() {
+    env::set_var("PROFILES_TEST_PROFILE", "dev");
+
+    let result = ConfigLoader::<ProfilesConfig>::new()
+        .code(CODE, Format::Yaml)
+        .unwrap()
+        .profile("prod")
+        .profile_from_env("PROFILES_TEST_PROFILE")
+        .load()
+        .unwrap();
+
+    env::remove_var("PROFILES_TEST_PROFILE");
+
+    assert_eq!(result.config.port, 8080);
+    assert_eq!(result.config.host, "localhost");
+}
+
+#[test]
+fn errors_for_invalid_profile_setting() {
+    let error = ConfigLoader::<ProfilesConfig>::new()
+        .code("profiles:\n  dev:\n    port: abc", Format::Yaml)
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Failed to parse ProfilesConfig. profiles.dev.port: invalid type: string \"abc\", expected usize"
+    );
+}
+
+#[test]
+fn errors_for_unknown_profile_setting() {
+    let error = ConfigLoader::<ProfilesConfig>::new()
+        .code("profiles:\n  dev:\n    prot: 80", Format::Yaml)
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Unknown setting profiles.dev.prot in ProfilesConfig. Did you mean `profiles.dev.port`? Expected one of `host`, `list`, `port`, `profiles`."
+    );
+}
+
+#[derive(Debug, Config)]
+pub struct NoProfilesConfig {
+    pub host: String,
+}
+
+#[test]
+fn profiles_are_unknown_when_not_enabled() {
+    let error = ConfigLoader::<NoProfilesConfig>::new()
+        .code("profiles:\n  dev:\n    host: abc", Format::Yaml)
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    assert!(matches!(error, ConfigError::UnknownSetting { .. }));
+}
+
+#[test]
+fn includes_profiles_in_schema() {
+    let schema = SchemaBuilder::build_root::<ProfilesConfig>();
+
+    let SchemaType::Struct(structure) = &schema.ty else {
+        panic!();
+    };
+
+    let profiles = structure.fields.get("profiles").unwrap();
+
+    assert!(profiles.optional);
+
+    let SchemaType::Object(object) = &profiles.ty else {
+        panic!();
+    };
+
+    assert_eq!(
+        object.value_type.name.as_deref(),
+        Some("PartialProfilesConfig")
+    );
+}
+
+#[cfg(feature = "renderer_json_schema")]
+#[test]
+fn renders_profiles_in_json_schema() {
+    use schematic::schema::json_schema::JsonSchemaRenderer;
+    use schematic::schema::SchemaGenerator;
+    use starbase_sandbox::{assert_snapshot, create_empty_sandbox};
+
+    let sandbox = create_empty_sandbox();
+    let file = sandbox.path().join("schema.json");
+
+    let mut generator = SchemaGenerator::default();
+    generator.add::<ProfilesConfig>();
+    generator
+        .generate(&file, JsonSchemaRenderer::default())
+        .unwrap();
+
+    assert_snapshot!(std::fs::read_to_string(file).unwrap());
+}
+
+#[cfg(feature = "renderer_typescript")]
+#[test]
+fn renders_profiles_in_typescript() {
+    use schematic::schema::typescript::TypeScriptRenderer;
+    use schematic::schema::SchemaGenerator;
+    use starbase_sandbox::{assert_snapshot, create_empty_sandbox};
+
+    let sandbox = create_empty_sandbox();
+    let file = sandbox.path().join("types.ts");
+
+    let mut generator = SchemaGenerator::default();
+    generator.add::<ProfilesConfig>();
+    generator
+        .generate(&file, TypeScriptRenderer::default())
+        .unwrap();
+
+    assert_snapshot!(std::fs::read_to_string(file).unwrap());
+}
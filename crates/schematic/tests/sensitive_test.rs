@@ -0,0 +1,273 @@
+use schematic::*;
+use serial_test::serial;
+use std::collections::HashMap;
+use std::env;
+
+fn validate_token<C>(value: &str, _: &C, _: &(), _: bool) -> ValidateResult {
+    if value.starts_with("tok_") {
+        Ok(())
+    } else {
+        Err(ValidateError::new(format!("{value} must start with tok_")))
+    }
+}
+
+#[derive(Config, serde::Serialize)]
+pub struct SensitiveConfig {
+    pub host: String,
+    #[setting(sensitive, env = "SCHEMATIC_SECRET_PORT")]
+    pub port: usize,
+    #[setting(sensitive, validate = validate_token)]
+    pub token: String,
+    #[setting(sensitive)]
+    pub password: Option<String>,
+}
+
+#[derive(Config, serde::Serialize)]
+pub struct SensitiveTuple(String, #[setting(sensitive)] String);
+
+fn validate_pin<C>(value: &usize, _: &C, _: &(), _: bool) -> ValidateResult {
+    if *value > 999 {
+        Ok(())
+    } else {
+        Err(ValidateError::new(format!("{value} is too short")))
+    }
+}
+
+#[derive(Config, serde::Serialize)]
+pub struct SensitiveNestedConfig {
+    #[setting(sensitive, validate = validate_pin)]
+    pub pin: usize,
+    #[setting(nested)]
+    pub primary: SensitiveTuple,
+    #[setting(nested)]
+    pub list: Vec<SensitiveConfig>,
+    #[setting(nested)]
+    pub map: HashMap<String, SensitiveConfig>,
+}
+
+#[test]
+fn redacts_debug() {
+    let partial = PartialSensitiveConfig {
+        host: Some("localhost".into()),
+        token: Some("tok_abc".into()),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        format!("{partial:?}"),
+        r#"PartialSensitiveConfig { host: Some("localhost"), port: None, token: Some([REDACTED]), password: None }"#
+    );
+
+    let config = SensitiveConfig::from_partial(partial);
+
+    assert_eq!(
+        format!("{config:?}"),
+        r#"SensitiveConfig { host: "localhost", port: [REDACTED], token: [REDACTED], password: None }"#
+    );
+
+    let tuple = SensitiveTuple("a".into(), "b".into());
+
+    assert_eq!(format!("{tuple:?}"), r#"SensitiveTuple("a", [REDACTED])"#);
+}
+
+#[test]
+fn redacts_serialization_when_enabled() {
+    let result = ConfigLoader::<SensitiveConfig>::new()
+        .code(
+            r#"{ "host": "localhost", "token": "tok_abc", "password": "hunter2" }"#,
+            Format::Json,
+        )
+        .unwrap()
+        .load()
+        .unwrap();
+
+    let raw = serde_json::to_string(&result).unwrap();
+
+    assert!(raw.contains("tok_abc"));
+    assert!(raw.contains("hunter2"));
+
+    let redacted = redact_sensitive(|| serde_json::to_string(&result).unwrap());
+
+    assert!(!redacted.contains("tok_abc"));
+    assert!(!redacted.contains("hunter2"));
+    assert!(redacted.contains(r#""host":"localhost""#));
+    assert!(redacted.contains(r#""token":"[REDACTED]""#));
+    assert!(redacted.contains(r#""password":"[REDACTED]""#));
+    assert!(!is_redacting());
+}
+
+#[test]
+fn redacts_nested_serialization_when_enabled() {
+    let result = ConfigLoader::<SensitiveNestedConfig>::new()
+        .code(
+            r#"{
+                "pin": 1234,
+                "primary": ["a", "secret1"],
+                "list": [{ "token": "tok_list" }],
+                "map": { "key": { "token": "tok_map", "port": 8080 } }
+            }"#,
+            Format::Json,
+        )
+        .unwrap()
+        .load()
+        .unwrap();
+
+    let redacted = redact_sensitive(|| serde_json::to_string(&result).unwrap());
+
+    assert!(!redacted.contains("1234"));
+    assert!(!redacted.contains("secret1"));
+    assert!(!redacted.contains("tok_list"));
+    assert!(!redacted.contains("tok_map"));
+    assert!(!redacted.contains("8080"));
+    assert!(redacted.contains(r#""primary":["a","[REDACTED]"]"#));
+}
+
+#[test]
+fn redacts_non_string_validation_errors() {
+    let error = ConfigLoader::<SensitiveNestedConfig>::new()
+        .code(r#"{ "pin": 123 }"#, Format::Json)
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    let message = error.to_full_string();
+
+    assert!(!message.contains("123"));
+    assert!(message.contains("[REDACTED] is too short"));
+}
+
+#[test]
+fn redacts_validation_errors() {
+    let error = ConfigLoader::<SensitiveConfig>::new()
+        .code(r#"{ "token": "abc123" }"#, Format::Json)
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    let message = error.to_full_string();
+
+    assert!(!message.contains("abc123"));
+    assert!(message.contains("[REDACTED] must start with tok_"));
+}
+
+#[test]
+#[serial]
+fn redacts_env_var_errors() {
+    env::set_var("SCHEMATIC_SECRET_PORT", "secret8080");
+
+    let error = ConfigLoader::<SensitiveConfig>::new()
+        .code(r#"{ "token": "tok_abc" }"#, Format::Json)
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    env::remove_var("SCHEMATIC_SECRET_PORT");
+
+    assert_eq!(
+        error.to_full_string(),
+        "Invalid environment variable SCHEMATIC_SECRET_PORT. Failed to parse \"[REDACTED]\" into the correct type."
+    );
+}
+
+fn validate_level<C>(value: &usize, _: &C, _: &(), _: bool) -> ValidateResult {
+    if *value > 1 {
+        Ok(())
+    } else {
+        Err(ValidateError::new(format!(
+            "{value} is below the minimum of 10 (21 total)"
+        )))
+    }
+}
+
+#[derive(Config, serde::Serialize)]
+pub struct SensitiveLevelConfig {
+    #[setting(sensitive, validate = validate_level, env = "SCHEMATIC_SECRET_LEVEL")]
+    pub level: usize,
+}
+
+#[test]
+fn redacts_non_string_values_as_whole_words() {
+    let error = ConfigLoader::<SensitiveLevelConfig>::new()
+        .code(r#"{ "level": 1 }"#, Format::Json)
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    assert!(error
+        .to_full_string()
+        .contains("[REDACTED] is below the minimum of 10 (21 total)"));
+}
+
+struct ChangingEnv(std::cell::Cell<usize>);
+
+impl EnvProvider for ChangingEnv {
+    fn get(&self, key: &str) -> Option<String> {
+        if key != "SCHEMATIC_SECRET_LEVEL" {
+            return None;
+        }
+
+        // Return a different value for each read
+        let count = self.0.get() + 1;
+        self.0.set(count);
+
+        Some(format!("secret{count}"))
+    }
+
+    fn vars(&self) -> Vec<(String, String)> {
+        vec![]
+    }
+}
+
+#[test]
+fn redacts_env_var_errors_with_the_value_that_was_read() {
+    let error = ConfigLoader::<SensitiveLevelConfig>::new()
+        .set_env_provider(ChangingEnv(std::cell::Cell::new(0)))
+        .load()
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Invalid environment variable SCHEMATIC_SECRET_LEVEL. Failed to parse \"[REDACTED]\" into the correct type."
+    );
+}
+
+#[cfg(feature = "renderer_json_schema")]
+#[test]
+fn generates_json_schema() {
+    use starbase_sandbox::{assert_snapshot, create_empty_sandbox};
+
+    let sandbox = create_empty_sandbox();
+    let file = sandbox.path().join("schema.json");
+
+    let mut generator = schema::SchemaGenerator::default();
+    generator.add::<SensitiveConfig>();
+    generator
+        .generate(&file, schema::json_schema::JsonSchemaRenderer::default())
+        .unwrap();
+
+    assert!(file.exists());
+    assert_snapshot!(std::fs::read_to_string(file).unwrap());
+}
+
+#[cfg(feature = "renderer_template")]
+#[test]
+fn generates_yaml_template() {
+    use starbase_sandbox::{assert_snapshot, create_empty_sandbox};
+
+    let sandbox = create_empty_sandbox();
+    let file = sandbox.path().join("config.yml");
+
+    let mut generator = schema::SchemaGenerator::default();
+    generator.add::<SensitiveConfig>();
+    generator
+        .generate(&file, schema::YamlTemplateRenderer::default())
+        .unwrap();
+
+    assert!(file.exists());
+    assert_snapshot!(std::fs::read_to_string(file).unwrap());
+}
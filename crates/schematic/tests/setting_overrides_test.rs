@@ -0,0 +1,128 @@
+use schematic::*;
+use std::collections::HashMap;
+
+#[derive(Debug, Config)]
+pub struct OverrideServer {
+    #[setting(default = "localhost")]
+    host: String,
+    #[setting(default = 80)]
+    port: usize,
+}
+
+#[derive(Debug, Config)]
+pub struct OverrideConfig {
+    debug: bool,
+    ratio: f32,
+    name: Option<String>,
+    list: Vec<String>,
+    map: HashMap<String, usize>,
+    #[setting(nested)]
+    server: OverrideServer,
+}
+
+fn load(loader: &mut ConfigLoader<OverrideConfig>) -> OverrideConfig {
+    loader
+        .code(
+            "list: [a, b]\nmap:\n  x: 1\nserver:\n  host: example.com",
+            Format::Yaml,
+        )
+        .unwrap();
+
+    loader.load().unwrap().config
+}
+
+#[test]
+fn overrides_nested_settings() {
+    let mut loader = ConfigLoader::<OverrideConfig>::new();
+    loader.set_override("server.port", "8080").unwrap();
+
+    let config = load(&mut loader);
+
+    assert_eq!(config.server.host, "example.com");
+    assert_eq!(config.server.port, 8080);
+}
+
+#[test]
+fn parses_into_setting_type() {
+    let mut loader = ConfigLoader::<OverrideConfig>::new();
+    loader
+        .overrides(["debug=true", "ratio=1.5", "name=test", "list=[\"c\"]"])
+        .unwrap();
+
+    let config = load(&mut loader);
+
+    assert!(config.debug);
+    assert_eq!(config.ratio, 1.5);
+    assert_eq!(config.name.as_deref(), Some("test"));
+    assert_eq!(config.list, vec!["c"]);
+}
+
+#[test]
+fn overrides_map_keys_and_list_indices() {
+    let mut loader = ConfigLoader::<OverrideConfig>::new();
+    loader
+        .overrides(["map.y=2", "list[1]=z", "list[2]=new"])
+        .unwrap();
+
+    let config = load(&mut loader);
+
+    assert_eq!(
+        config.map,
+        HashMap::from_iter([("x".into(), 1), ("y".into(), 2)])
+    );
+    assert_eq!(config.list, vec!["a", "z", "new"]);
+}
+
+#[test]
+fn errors_for_unknown_path() {
+    let error = ConfigLoader::<OverrideConfig>::new()
+        .set_override("server.prot", "8080")
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Unable to override unknown setting server.prot. Did you mean `server.port`? Expected one of `debug`, `list`, `map`, `name`, `ratio`, `server`, `server.host`, `server.port`."
+    );
+
+    let error = ConfigLoader::<OverrideConfig>::new()
+        .set_override("debug.nested", "true")
+        .err()
+        .unwrap();
+
+    assert!(matches!(error, ConfigError::UnknownOverrideSetting { .. }));
+}
+
+#[test]
+fn errors_for_invalid_value() {
+    let error = ConfigLoader::<OverrideConfig>::new()
+        .set_override("server.port", "abc")
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Invalid override for setting server.port. Expected an integer, received `abc`."
+    );
+
+    let error = ConfigLoader::<OverrideConfig>::new()
+        .overrides(["debug"])
+        .err()
+        .unwrap();
+
+    assert!(matches!(error, ConfigError::InvalidOverrideValue { .. }));
+}
+
+#[test]
+fn errors_for_out_of_bounds_index() {
+    let mut loader = ConfigLoader::<OverrideConfig>::new();
+    loader.set_override("list[5]", "x").unwrap();
+    loader.code("list: [a]", Format::Yaml).unwrap();
+
+    let error = loader.load().err().unwrap();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Invalid override for setting list[5]. Index 5 is out of bounds, list has 1 items."
+    );
+}
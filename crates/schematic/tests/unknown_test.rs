@@ -0,0 +1,196 @@
+use schematic::*;
+use std::collections::HashMap;
+
+#[derive(Debug, Config)]
+pub struct ServerConfig {
+    pub host: String,
+    pub port: usize,
+}
+
+#[derive(Debug, Config)]
+pub struct UnknownConfig {
+    pub name: String,
+    #[setting(nested)]
+    pub server: ServerConfig,
+    #[setting(nested)]
+    pub servers: HashMap<String, ServerConfig>,
+    #[setting(nested)]
+    pub list: Vec<ServerConfig>,
+}
+
+#[derive(Debug, Config)]
+#[config(allow_unknown_fields)]
+pub struct AllowUnknownConfig {
+    pub name: String,
+    #[setting(nested)]
+    pub server: ServerConfig,
+}
+
+#[derive(Debug, Config)]
+pub struct AllowUnknownNested {
+    #[setting(nested)]
+    pub nested: AllowUnknownConfig,
+}
+
+fn load_error(code: &str) -> ConfigError {
+    ConfigLoader::<UnknownConfig>::new()
+        .code(code, Format::Yaml)
+        .unwrap()
+        .load()
+        .err()
+        .unwrap()
+}
+
+#[test]
+fn errors_for_unknown_root_setting() {
+    let error = load_error("nmae: abc");
+
+    assert_eq!(
+        error.to_full_string(),
+        "Unknown setting nmae in UnknownConfig. Did you mean `name`? Expected one of `list`, `name`, `server`, `servers`."
+    );
+
+    let ConfigError::UnknownSetting { span, .. } = error else {
+        panic!();
+    };
+
+    assert_eq!(span, Some((0, 4).into()));
+}
+
+#[test]
+fn suggests_nested_setting_paths() {
+    let error = load_error("serverPort: 80");
+
+    assert_eq!(
+        error.to_full_string(),
+        "Unknown setting serverPort in UnknownConfig. Did you mean `server.port`? Expected one of `list`, `name`, `server`, `servers`."
+    );
+}
+
+#[test]
+fn errors_for_unknown_nested_setting() {
+    let error = load_error("server:\n  hots: localhost");
+
+    assert_eq!(
+        error.to_full_string(),
+        "Unknown setting server.hots in UnknownConfig. Did you mean `server.host`? Expected one of `host`, `port`."
+    );
+
+    let ConfigError::UnknownSetting { span, .. } = error else {
+        panic!();
+    };
+
+    assert_eq!(span, Some((10, 4).into()));
+}
+
+#[test]
+fn suggests_relative_to_collections() {
+    let error = load_error("servers:\n  a:\n    prot: 80");
+
+    assert_eq!(
+        error.to_full_string(),
+        "Unknown setting servers.a.prot in UnknownConfig. Did you mean `servers.a.port`? Expected one of `host`, `port`."
+    );
+
+    let error = load_error("list:\n  - host: localhost\n  - pot: 80");
+
+    assert_eq!(
+        error.to_full_string(),
+        "Unknown setting list[1].pot in UnknownConfig. Did you mean `list[1].port`? Expected one of `host`, `port`."
+    );
+}
+
+#[test]
+fn omits_suggestion_when_not_similar() {
+    let error = load_error("something: true");
+
+    assert_eq!(
+        error.to_full_string(),
+        "Unknown setting something in UnknownConfig. Expected one of `list`, `name`, `server`, `servers`."
+    );
+}
+
+#[test]
+fn warns_when_unknown_allowed() {
+    let result = ConfigLoader::<AllowUnknownConfig>::new()
+        .code("nmae: abc", Format::Yaml)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(
+        result
+            .warnings
+            .iter()
+            .map(|warning| warning.to_full_string())
+            .collect::<Vec<_>>(),
+        ["Unknown setting nmae in AllowUnknownConfig was ignored. Did you mean `name`? Expected one of `name`, `server`."]
+    );
+}
+
+#[test]
+fn errors_when_nested_denies_unknown() {
+    let error = ConfigLoader::<AllowUnknownConfig>::new()
+        .code("server:\n  hots: localhost", Format::Yaml)
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Unknown setting server.hots in AllowUnknownConfig. Did you mean `server.host`? Expected one of `host`, `port`."
+    );
+}
+
+#[test]
+fn warns_when_nested_allows_unknown() {
+    let result = ConfigLoader::<AllowUnknownNested>::new()
+        .code("nested:\n  nmae: abc", Format::Yaml)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(
+        result
+            .warnings
+            .iter()
+            .map(|warning| warning.to_full_string())
+            .collect::<Vec<_>>(),
+        ["Unknown setting nested.nmae in AllowUnknownNested was ignored. Did you mean `nested.name`? Expected one of `name`, `server`."]
+    );
+}
+
+#[derive(Debug, Config)]
+pub struct FlattenConfig {
+    pub name: String,
+    #[setting(nested)]
+    pub server: ServerConfig,
+    #[setting(flatten)]
+    pub rest: HashMap<String, serde_json::Value>,
+}
+
+#[test]
+fn doesnt_error_for_keys_captured_by_flatten() {
+    let result = ConfigLoader::<FlattenConfig>::new()
+        .code("name: abc\nextra: 123\nother: true", Format::Yaml)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.name, "abc");
+    assert_eq!(result.config.rest.len(), 2);
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn still_errors_for_unknown_nested_settings_with_flatten() {
+    let error = ConfigLoader::<FlattenConfig>::new()
+        .code("server:\n  hots: abc", Format::Yaml)
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    assert!(matches!(error, ConfigError::UnknownSetting { .. }));
+}
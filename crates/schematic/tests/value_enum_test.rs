@@ -0,0 +1,85 @@
+use clap::ValueEnum;
+use schematic::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, ConfigEnum, Debug, Deserialize, PartialEq, Serialize)]
+#[config(value_enum)]
+enum LogLevel {
+    /// Only errors.
+    Fatal,
+    #[serde(alias = "warning")]
+    Warn,
+    Info,
+    #[variant(fallback)]
+    Other(String),
+}
+
+#[derive(Clone, ConfigEnum, Debug, PartialEq)]
+#[config(before_parse = "lowercase", value_enum)]
+enum Color {
+    Red,
+    #[variant(value = "dark-blue")]
+    Blue,
+}
+
+#[test]
+fn lists_unit_variants() {
+    assert_eq!(
+        LogLevel::value_variants(),
+        &[LogLevel::Fatal, LogLevel::Warn, LogLevel::Info]
+    );
+    assert_eq!(Color::value_variants(), &[Color::Red, Color::Blue]);
+}
+
+#[test]
+fn creates_possible_values() {
+    let value = LogLevel::Fatal.to_possible_value().unwrap();
+
+    assert_eq!(value.get_name(), "fatal");
+    assert_eq!(value.get_help().unwrap().to_string(), "Only errors.");
+    assert!(!value.is_hide_set());
+
+    let value = LogLevel::Warn.to_possible_value().unwrap();
+
+    assert!(value.matches("warning", false));
+
+    let value = LogLevel::Other("debug".into()).to_possible_value().unwrap();
+
+    assert_eq!(value.get_name(), "debug");
+    assert!(value.is_hide_set());
+
+    assert_eq!(
+        Color::Blue.to_possible_value().unwrap().get_name(),
+        "dark-blue"
+    );
+}
+
+#[test]
+fn parses_from_str() {
+    assert_eq!(
+        <LogLevel as ValueEnum>::from_str("info", false).unwrap(),
+        LogLevel::Info
+    );
+    assert_eq!(
+        <LogLevel as ValueEnum>::from_str("trace", false).unwrap(),
+        LogLevel::Other("trace".into())
+    );
+    assert_eq!(
+        <Color as ValueEnum>::from_str("RED", false).unwrap(),
+        Color::Red
+    );
+    assert!(<Color as ValueEnum>::from_str("green", false).is_err());
+}
+
+#[test]
+fn works_as_clap_argument() {
+    let matches = clap::Command::new("app")
+        .arg(
+            clap::Arg::new("color")
+                .long("color")
+                .value_parser(clap::builder::EnumValueParser::<Color>::new()),
+        )
+        .get_matches_from(["app", "--color", "dark-blue"]);
+
+    assert_eq!(matches.get_one::<Color>("color"), Some(&Color::Blue));
+}
@@ -0,0 +1,271 @@
+use schematic::*;
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Debug, Config)]
+#[config(allow_unknown_fields)]
+pub struct NestedWarnings {
+    pub value: String,
+}
+
+#[derive(Debug, Config)]
+#[config(allow_unknown_fields)]
+pub struct WarningsConfig {
+    #[setting(default = 10)]
+    pub count: usize,
+    #[setting(alias = "aliasedName")]
+    pub name: String,
+    #[setting(merge = merge::append_vec)]
+    pub list: Vec<String>,
+    #[setting(nested)]
+    pub nested: NestedWarnings,
+    #[setting(nested)]
+    pub map: HashMap<String, NestedWarnings>,
+}
+
+fn to_strings(warnings: &[ConfigWarning]) -> Vec<String> {
+    let mut list = warnings
+        .iter()
+        .map(|warning| warning.to_full_string())
+        .collect::<Vec<_>>();
+
+    list.sort();
+    list
+}
+
+#[test]
+fn no_warnings_for_valid_config() {
+    let result = ConfigLoader::<WarningsConfig>::new()
+        .code(
+            r#"{ "count": 5, "aliasedName": "abc", "nested": { "value": "x" } }"#,
+            Format::Json,
+        )
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn warns_for_unknown_settings() {
+    let result = ConfigLoader::<WarningsConfig>::new()
+        .code(
+            r#"{ "unknown": 1, "nested": { "other": true }, "map": { "a": { "foo": 1 } } }"#,
+            Format::Json,
+        )
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(
+        to_strings(&result.warnings),
+        [
+            "Unknown setting map.a.foo in WarningsConfig was ignored. Expected one of `value`.",
+            "Unknown setting nested.other in WarningsConfig was ignored. Expected one of `value`.",
+            "Unknown setting unknown in WarningsConfig was ignored. Expected one of `count`, `list`, `map`, `name`, `nested`.",
+        ]
+    );
+
+    let span = result.warnings.iter().find_map(|warning| match warning {
+        ConfigWarning::UnknownSetting { path, span, .. } if path == "unknown" => *span,
+        _ => None,
+    });
+
+    assert_eq!(span, Some((3, 7).into()));
+}
+
+#[test]
+fn doesnt_warn_for_values_equal_to_default_by_default() {
+    let result = ConfigLoader::<WarningsConfig>::new()
+        .code("count: 10\nname: abc", Format::Yaml)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn warns_for_values_equal_to_default() {
+    let result = ConfigLoader::<WarningsConfig>::new()
+        .warn_redundant_settings()
+        .code("count: 10\nname: abc", Format::Yaml)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(
+        to_strings(&result.warnings),
+        ["Setting count in WarningsConfig is equal to its default or inherited value, and can be removed."]
+    );
+}
+
+#[test]
+fn warns_for_values_equal_to_previous_layer() {
+    let result = ConfigLoader::<WarningsConfig>::new()
+        .warn_redundant_settings()
+        .code("count: 5\nname: abc\nlist: [a]", Format::Yaml)
+        .unwrap()
+        .code("count: 5\nname: xyz\nlist: [a]", Format::Yaml)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.list, vec!["a", "a"]);
+    assert_eq!(
+        to_strings(&result.warnings),
+        ["Setting count in WarningsConfig is equal to its default or inherited value, and can be removed."]
+    );
+}
+
+#[test]
+fn doesnt_warn_when_overriding_previous_layer_with_default() {
+    let result = ConfigLoader::<WarningsConfig>::new()
+        .warn_redundant_settings()
+        .code("count: 5", Format::Yaml)
+        .unwrap()
+        .code("count: 10", Format::Yaml)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.count, 10);
+    assert!(result.warnings.is_empty());
+}
+
+// Only fails when merging directly into the default value, which
+// only happens while inspecting for redundant values
+fn merge_unless_default<C>(prev: usize, next: usize, _: &C) -> MergeResult<usize> {
+    if prev == 10 && next == 5 {
+        return Err(HandlerError::new("Cannot be merged."));
+    }
+
+    Ok(Some(next))
+}
+
+#[derive(Debug, Config)]
+pub struct MergeConfig {
+    #[setting(default = 10, merge = merge_unless_default)]
+    pub count: usize,
+}
+
+#[test]
+fn doesnt_fail_when_inspecting_values_fails() {
+    let result = ConfigLoader::<MergeConfig>::new()
+        .warn_redundant_settings()
+        .code("count: 5", Format::Yaml)
+        .unwrap()
+        .code("count: 7", Format::Yaml)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.count, 7);
+}
+
+fn warn_if_empty<D, C>(value: &String, _: &D, _: &C, _: bool) -> Result<(), ValidateError> {
+    if value.is_empty() {
+        return Err(ValidateError::warning("should not be empty"));
+    }
+
+    if value == "fail" {
+        return Err(ValidateError::new("cannot be fail"));
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Config)]
+pub struct ValidateWarnings {
+    #[setting(validate = warn_if_empty)]
+    pub value: String,
+    #[setting(validate = warn_if_empty)]
+    pub other: String,
+}
+
+#[test]
+fn validators_can_emit_warnings() {
+    let result = ConfigLoader::<ValidateWarnings>::new()
+        .code("value: abc", Format::Yaml)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.value, "abc");
+    assert_eq!(
+        to_strings(&result.warnings),
+        ["Validation warnings for ValidateWarnings.\n  other: should not be empty"]
+    );
+}
+
+#[test]
+fn validator_errors_still_fail_with_warnings() {
+    let error = ConfigLoader::<ValidateWarnings>::new()
+        .code("value: fail", Format::Yaml)
+        .unwrap()
+        .load()
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        error.to_full_string(),
+        "Failed to validate ValidateWarnings. \n  value: cannot be fail"
+    );
+}
+
+#[derive(Default)]
+struct StaleCache;
+
+impl Cacher for StaleCache {
+    fn read(&mut self, _url: &str) -> Result<Option<String>, ConfigError> {
+        Ok(None)
+    }
+
+    fn read_stale(&mut self, _url: &str) -> Result<Option<String>, ConfigError> {
+        Ok(Some("name: stale".into()))
+    }
+
+    fn write(&mut self, _url: &str, _content: &str) -> Result<(), ConfigError> {
+        Ok(())
+    }
+}
+
+#[test]
+fn uses_stale_cache_when_url_fails() {
+    let result = ConfigLoader::<WarningsConfig>::new()
+        .set_cacher(StaleCache)
+        .url("https://missing.invalid/config.yml")
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.name, "stale");
+    assert_eq!(result.warnings.len(), 1);
+    assert!(matches!(
+        result.warnings[0],
+        ConfigWarning::StaleCache { .. }
+    ));
+}
+
+#[test]
+fn uses_expired_memory_cache_when_url_fails() {
+    let url = "https://missing.invalid/config.yml";
+    let mut cacher = MemoryCache::with_ttl(Duration::ZERO);
+
+    cacher.write(url, "name: expired").unwrap();
+
+    let result = ConfigLoader::<WarningsConfig>::new()
+        .set_cacher(cacher)
+        .url(url)
+        .unwrap()
+        .load()
+        .unwrap();
+
+    assert_eq!(result.config.name, "expired");
+    assert_eq!(result.warnings.len(), 1);
+    assert!(matches!(
+        result.warnings[0],
+        ConfigWarning::StaleCache { .. }
+    ));
+}
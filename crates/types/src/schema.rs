@@ -10,12 +10,16 @@ pub struct Schema {
     pub ty: SchemaType,
 
     // States
+    pub alias: Option<String>,
     pub deprecated: Option<String>,
-    pub env_var: Option<String>,
+    pub deprecated_env_var: Vec<String>,
+    pub env_var: Vec<String>,
+    pub flatten: bool,
     pub hidden: bool,
     pub nullable: bool,
     pub optional: bool,
     pub read_only: bool,
+    pub renamed_from: Option<String>,
     pub write_only: bool,
 }
 
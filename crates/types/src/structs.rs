@@ -3,6 +3,8 @@ use std::collections::BTreeMap;
 
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct StructType {
+    // Unknown fields will fail deserialization, instead of being ignored.
+    pub deny_unknown_fields: bool,
     pub fields: BTreeMap<String, Box<Schema>>,
     // The type is a partial nested config, like `PartialConfig`.
     // This doesn't mean it's been partialized.
@@ -25,4 +27,25 @@ impl StructType {
     pub fn is_hidden(&self) -> bool {
         self.fields.values().all(|field| field.hidden)
     }
+
+    /// Return all fields, including an optional and deprecated field for each
+    /// previous name that a field was renamed from.
+    pub fn fields_with_renamed(&self) -> BTreeMap<String, Box<Schema>> {
+        let mut fields = self.fields.clone();
+
+        for (name, field) in &self.fields {
+            let Some(old_name) = &field.renamed_from else {
+                continue;
+            };
+
+            let mut old_field = field.clone();
+            old_field.deprecated = Some(format!("Renamed to `{name}`."));
+            old_field.optional = true;
+            old_field.renamed_from = None;
+
+            fields.insert(old_name.to_owned(), old_field);
+        }
+
+        fields
+    }
 }
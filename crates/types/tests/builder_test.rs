@@ -230,6 +230,7 @@ fn supports_cycles() {
     assert_eq!(
         test_builder::<Cycle>().ty,
         SchemaType::Struct(Box::new(StructType {
+            deny_unknown_fields: false,
             fields: BTreeMap::from_iter([(
                 "values".into(),
                 Box::new(Schema::object(ObjectType::new(